@@ -3,8 +3,9 @@ use itertools::Itertools;
 use rethabi::{
     decode, encode,
     param_type::{ParamType, Reader},
+    selector_collisions,
     token::{LenientTokenizer, StrictTokenizer, Token, Tokenizer},
-    Contract, Event, Function, Hash,
+    Artifact, Contract, Event, Formatter, Function, Hash, RawLog, Severity,
 };
 use sha3::{Digest, Keccak256};
 use std::fs::File;
@@ -17,11 +18,28 @@ enum Opt {
     Encode(Encode),
     /// Decode ABI call result.
     Decode(Decode),
+    /// Check JSON ABI file for structural problems.
+    Validate { abi_path: String },
+    /// Render calls and logs as trees of named values.
+    Format(Format),
+    /// Find functions of the contracts sharing a selector, e.g. the facets of a diamond.
+    Collisions {
+        /// JSON ABI files or build artifacts of the contracts.
+        #[structopt(required = true)]
+        abi_paths: Vec<String>,
+    },
+    /// Render JSON ABI file or build artifact as a Solidity interface.
+    Interface {
+        abi_path: String,
+        /// Name of the interface.
+        #[structopt(short, long, default_value = "IContract")]
+        name: String,
+    },
 }
 
 #[derive(StructOpt, Debug)]
 enum Encode {
-    /// Load function from JSON ABI file.
+    /// Load function from JSON ABI file or build artifact.
     Function {
         abi_path: String,
         function_name_or_signature: String,
@@ -51,7 +69,7 @@ enum Encode {
 
 #[derive(StructOpt, Debug)]
 enum Decode {
-    /// Load function from JSON ABI file.
+    /// Load function from JSON ABI file or build artifact.
     Function { abi_path: String, function_name_or_signature: String, data: String },
     /// Specify types of input params inline.
     Params {
@@ -69,6 +87,28 @@ enum Decode {
     },
 }
 
+#[derive(StructOpt, Debug)]
+enum Format {
+    /// Render calldata of a function call, and the data it returned if given.
+    Call {
+        /// JSON ABI files or build artifacts of the contracts the function may belong to.
+        #[structopt(short = "a", long = "abi", number_of_values = 1, required = true)]
+        abi_paths: Vec<String>,
+        data: String,
+        #[structopt(short, long)]
+        output: Option<String>,
+    },
+    /// Render event log.
+    Log {
+        /// JSON ABI files or build artifacts of the contracts the event may belong to.
+        #[structopt(short = "a", long = "abi", number_of_values = 1, required = true)]
+        abi_paths: Vec<String>,
+        #[structopt(short = "l", name = "topic", number_of_values = 1)]
+        topics: Vec<String>,
+        data: String,
+    },
+}
+
 fn main() -> anyhow::Result<()> {
     println!("{}", execute(std::env::args())?);
 
@@ -94,12 +134,28 @@ where
         Opt::Decode(Decode::Log { abi_path, event_name_or_signature, topics, data }) => {
             decode_log(&abi_path, &event_name_or_signature, &topics, &data)
         }
+        Opt::Validate { abi_path } => validate(&abi_path),
+        Opt::Format(Format::Call { abi_paths, data, output }) => {
+            format_call(&abi_paths, &data, output.as_deref())
+        }
+        Opt::Format(Format::Log { abi_paths, topics, data }) => {
+            format_log(&abi_paths, &topics, &data)
+        }
+        Opt::Collisions { abi_paths } => collisions(&abi_paths),
+        Opt::Interface { abi_path, name } => {
+            Ok(load_contract(&abi_path)?.to_solidity_interface(&name).trim_end().to_owned())
+        }
     }
 }
 
-fn load_function(path: &str, name_or_signature: &str) -> anyhow::Result<Function> {
+/// Loads the contract from a JSON ABI file or a build artifact.
+fn load_contract(path: &str) -> anyhow::Result<Contract> {
     let file = File::open(path)?;
-    let contract = Contract::load(file)?;
+    Ok(Artifact::load(file)?.contract)
+}
+
+fn load_function(path: &str, name_or_signature: &str) -> anyhow::Result<Function> {
+    let contract = load_contract(path)?;
     let params_start = name_or_signature.find('(');
 
     match params_start {
@@ -131,8 +187,7 @@ fn load_function(path: &str, name_or_signature: &str) -> anyhow::Result<Function
 }
 
 fn load_event(path: &str, name_or_signature: &str) -> anyhow::Result<Event> {
-    let file = File::open(path)?;
-    let contract = Contract::load(file)?;
+    let contract = load_contract(path)?;
     let params_start = name_or_signature.find('(');
 
     match params_start {
@@ -268,6 +323,69 @@ fn decode_log(
     Ok(result)
 }
 
+fn validate(path: &str) -> anyhow::Result<String> {
+    let file = File::open(path)?;
+    let diagnostics = Contract::validate_json(file)?;
+
+    let result = diagnostics.iter().map(ToString::to_string).collect::<Vec<String>>().join("\n");
+
+    match diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+        true => Err(anyhow!(result)),
+        false => Ok(result),
+    }
+}
+
+fn format_call(paths: &[String], data: &str, output: Option<&str>) -> anyhow::Result<String> {
+    let contracts = paths.iter().map(|path| load_contract(path)).collect::<Result<Vec<_>, _>>()?;
+    let mut formatter = Formatter::new();
+    for contract in &contracts {
+        formatter.add_contract(contract);
+    }
+
+    let data = hex::decode(data)?;
+    let mut result = formatter.format_call(&data)?;
+    if let Some(output) = output {
+        result.push('\n');
+        result.push_str(&formatter.format_output(&data, &hex::decode(output)?)?);
+    }
+    Ok(result)
+}
+
+fn format_log(paths: &[String], topics: &[String], data: &str) -> anyhow::Result<String> {
+    let contracts = paths.iter().map(|path| load_contract(path)).collect::<Result<Vec<_>, _>>()?;
+    let mut formatter = Formatter::new();
+    for contract in &contracts {
+        formatter.add_contract(contract);
+    }
+
+    let topics = topics.iter().map(|t| t.parse()).collect::<Result<_, _>>()?;
+    let log = RawLog { topics, data: hex::decode(data)? };
+    Ok(formatter.format_log(&log)?)
+}
+
+fn collisions(paths: &[String]) -> anyhow::Result<String> {
+    let contracts = paths.iter().map(|path| load_contract(path)).collect::<Result<Vec<_>, _>>()?;
+    let collisions = selector_collisions(&contracts);
+
+    let result = collisions
+        .iter()
+        .map(|collision| {
+            let functions = collision
+                .functions
+                .iter()
+                .zip(collision.signatures())
+                .map(|((index, _), signature)| format!("{signature} in {}", paths[*index]))
+                .join(", ");
+            format!("0x{}: {}", hex::encode(collision.selector), functions)
+        })
+        .join("\n");
+
+    match collisions.is_empty() {
+        true => Ok(result),
+        false => Err(anyhow!(result)),
+    }
+}
+
 fn hash_signature(sig: &str) -> Hash {
     Hash::from_slice(Keccak256::digest(sig.replace(' ', "").as_bytes()).as_slice())
 }
@@ -349,6 +467,13 @@ mod tests {
         assert_eq!(execute(command).unwrap_err().to_string(), "int256 parse error: Overflow");
     }
 
+    #[test]
+    fn fixed_encode() {
+        let command = "rethabi encode params -v fixed128x18 -1.5".split(' ');
+        let expected = "ffffffffffffffffffffffffffffffffffffffffffffffffeb2eedf284ea0000";
+        assert_eq!(execute(command).unwrap(), expected);
+    }
+
     #[test]
     fn multi_encode() {
         let command = "rethabi encode params -v bool 1 -v string gavofyork -v bool 0".split(' ');
@@ -425,6 +550,15 @@ mod tests {
         assert_eq!(execute(command).unwrap(), expected);
     }
 
+    #[test]
+    fn fixed_decode() {
+        let command =
+			"rethabi decode params -t ufixed64x2 0000000000000000000000000000000000000000000000000000000000000096"
+				.split(' ');
+        let expected = "ufixed64x2 1.5";
+        assert_eq!(execute(command).unwrap(), expected);
+    }
+
     #[test]
     fn multi_decode() {
         let command = "rethabi decode params -t bool -t string -t bool 00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096761766f66796f726b0000000000000000000000000000000000000000000000".split(' ');
@@ -448,6 +582,13 @@ bool false";
         assert_eq!(execute(command).unwrap(), expected);
     }
 
+    #[test]
+    fn artifact_decode() {
+        let command = "rethabi decode function ../res/foo.artifact.json bar 0000000000000000000000000000000000000000000000000000000000000001".split(' ');
+        let expected = "bool true";
+        assert_eq!(execute(command).unwrap(), expected);
+    }
+
     #[test]
     fn log_decode() {
         let command = "rethabi decode log ../res/event.abi Event -l 0000000000000000000000000000000000000000000000000000000000000001 0000000000000000000000004444444444444444444444444444444444444444".split(' ');
@@ -470,4 +611,64 @@ b 4444444444444444444444444444444444444444";
         let command = "rethabi decode log ../res/event.abi Nope(bool,address) -l 0000000000000000000000000000000000000000000000000000000000000000 0000000000000000000000004444444444444444444444444444444444444444".split(' ');
         assert!(execute(command).is_err());
     }
+
+    #[test]
+    fn validate() {
+        let command = "rethabi validate ../res/eip20.abi".split(' ');
+        assert_eq!(execute(command).unwrap(), "");
+
+        let command = "rethabi validate ../res/invalid.abi".split(' ');
+        let expected = "error: constructor: constructors can not have outputs
+error: event Transfer(address,address,uint256,uint256): 4 indexed params, at most 3 are allowed";
+        assert_eq!(execute(command).unwrap_err().to_string(), expected);
+    }
+
+    #[test]
+    fn collisions() {
+        let command = "rethabi collisions ../res/eip20.abi ../res/collision.abi".split(' ');
+        let expected = "0x23b872dd: transferFrom(address,address,uint256) in ../res/eip20.abi, gasprice_bit_ether(int128) in ../res/collision.abi
+0xa9059cbb: transfer(address,uint256) in ../res/eip20.abi, many_msg_babbage(bytes1) in ../res/collision.abi";
+        assert_eq!(execute(command).unwrap_err().to_string(), expected);
+
+        let command = "rethabi collisions ../res/eip20.abi ../res/foo.abi".split(' ');
+        assert_eq!(execute(command).unwrap(), "");
+    }
+
+    #[test]
+    fn interface() {
+        let command = "rethabi interface ../res/foo.abi -n IFoo".split(' ');
+        let expected = "// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.4;
+
+interface IFoo {
+    function bar(address hello) external returns (bool);
+}";
+        assert_eq!(execute(command).unwrap(), expected);
+    }
+
+    #[test]
+    fn format_call() {
+        let command = "rethabi format call -a ../res/test.abi -a ../res/foo.abi 646ea56d0000000000000000000000004444444444444444444444444444444444444444 -o 0000000000000000000000000000000000000000000000000000000000000001".split(' ');
+        let expected = "bar(
+  hello: 0x4444444444444444444444444444444444444444
+)
+bar -> (
+  true
+)";
+        assert_eq!(execute(command).unwrap(), expected);
+
+        let command = "rethabi format call -a ../res/test.abi 646ea56d0000000000000000000000004444444444444444444444444444444444444444".split(' ');
+        assert!(execute(command).is_err());
+    }
+
+    #[test]
+    fn format_log() {
+        let command = "rethabi format log -a ../res/eip20.abi -l ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef -l 0000000000000000000000001111111111111111111111111111111111111111 -l 0000000000000000000000002222222222222222222222222222222222222222 0000000000000000000000000000000000000000000000000000000000000005".split(' ');
+        let expected = "Transfer(
+  from: 0x1111111111111111111111111111111111111111,
+  to: 0x2222222222222222222222222222222222222222,
+  value: 5
+)";
+        assert_eq!(execute(command).unwrap(), expected);
+    }
 }
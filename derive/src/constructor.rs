@@ -10,8 +10,8 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use super::{
-    from_template_param, get_template_names, input_names, rust_type, template_param_type,
-    to_ethabi_param_vec, to_token,
+    get_template_names, input_names, to_ethabi_param_vec,
+    types::{param_rust_type, param_template_type, param_to_token},
 };
 
 /// Structure used to generate contract's constructor interface.
@@ -24,6 +24,9 @@ pub struct Constructor {
 
 impl<'a> From<&'a rethabi::Constructor> for Constructor {
     fn from(c: &'a rethabi::Constructor) -> Self {
+        // Generated enums and user-defined value types live next to the constructor.
+        let types = quote! { types };
+
         // [param0, hello_world, param2]
         let input_names = input_names(&c.inputs);
 
@@ -32,11 +35,11 @@ impl<'a> From<&'a rethabi::Constructor> for Constructor {
             .inputs
             .iter()
             .enumerate()
-            .map(|(index, param)| template_param_type(&param.kind, index))
+            .map(|(index, param)| param_template_type(param, index, &types))
             .collect();
 
         // [Uint, Bytes, Vec<Uint>]
-        let kinds: Vec<_> = c.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+        let kinds: Vec<_> = c.inputs.iter().map(|param| param_rust_type(param, &types)).collect();
 
         // [T0, T1, T2]
         let template_names: Vec<_> = get_template_names(&kinds);
@@ -55,9 +58,7 @@ impl<'a> From<&'a rethabi::Constructor> for Constructor {
         let tokenize: Vec<_> = input_names
             .iter()
             .zip(c.inputs.iter())
-            .map(|(param_name, param)| {
-                to_token(&from_template_param(&param.kind, param_name), &param.kind)
-            })
+            .map(|(param_name, param)| param_to_token(param, param_name, &types))
             .collect();
 
         Constructor {
@@ -119,11 +120,7 @@ mod tests {
     #[ignore = "TODO"]
     fn test_one_param() {
         let ethabi_constructor = rethabi::Constructor {
-            inputs: vec![rethabi::Param {
-                name: "foo".into(),
-                kind: rethabi::ParamType::Uint(256),
-                internal_type: None,
-            }],
+            inputs: vec![rethabi::Param::new("foo", rethabi::ParamType::Uint(256))],
         };
 
         let c = Constructor::from(&ethabi_constructor);
@@ -132,11 +129,7 @@ mod tests {
             /// Encodes a call to contract's constructor.
             pub fn constructor<T0: Into<rethabi::Uint> >(code: rethabi::Bytes, foo: T0) -> rethabi::Bytes {
                 let c = rethabi::Constructor {
-                    inputs: vec![rethabi::Param {
-                        name: "foo".to_owned(),
-                        kind: rethabi::ParamType::Uint(256usize),
-                        internal_type: None
-                    }],
+                    inputs: vec![rethabi::Param::new("foo", rethabi::ParamType::Uint(256usize))],
                 };
                 let tokens = vec![rethabi::Token::Uint(Uint::from(foo))];
                 c.encode_input(code, &tokens).expect(INTERNAL_ERR)
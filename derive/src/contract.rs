@@ -6,39 +6,104 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::borrow::Cow;
+
 use proc_macro2::TokenStream;
 use quote::quote;
+use rethabi::{Error, Result};
 
-use crate::{constructor::Constructor, event::Event, function::Function};
+use crate::{constructor::Constructor, event::Event, function::Function, types::UserType};
 
 /// Structure used to generate rust interface for solidity contract.
 pub struct Contract {
     constructor: Option<Constructor>,
     functions: Vec<Function>,
     events: Vec<Event>,
+    types: Vec<UserType>,
+    /// Solidity types generating a type with the name of another one.
+    clashes: Vec<String>,
+    interface_id: [u8; 4],
 }
 
 impl<'a> From<&'a rethabi::Contract> for Contract {
     fn from(c: &'a rethabi::Contract) -> Self {
+        let params = c.constructor.iter().flat_map(|constructor| constructor.inputs.iter()).chain(
+            c.functions().flat_map(|function| function.inputs.iter().chain(&function.outputs)),
+        );
+
+        let event_params = c.events().flat_map(|event| event.inputs.iter());
+
+        let mut types: Vec<UserType> = Vec::new();
+        let mut clashes = Vec::new();
+        let user_types = params
+            .filter_map(UserType::from_param)
+            .chain(event_params.filter_map(UserType::from_param));
+        for user_type in user_types {
+            match types.iter().find(|t| t.name() == user_type.name()) {
+                None => types.push(user_type),
+                Some(t) if t.is_same(&user_type) => {}
+                Some(t) => clashes.push(format!(
+                    "`{}` and `{}` both generate the type `{}`",
+                    t.internal_type(),
+                    user_type.internal_type(),
+                    user_type.name()
+                )),
+            }
+        }
+
         Contract {
             constructor: c.constructor.as_ref().map(Into::into),
             functions: c.functions().map(Into::into).collect(),
             events: c.events().map(Into::into).collect(),
+            types,
+            clashes,
+            interface_id: c.interface_id(),
         }
     }
 }
 
 impl Contract {
+    /// Declares the names of the members of a generated enum, which the ABI does not carry.
+    pub fn declare_enum(&mut self, name: &syn::Ident, variants: Vec<syn::Ident>) -> Result<()> {
+        let user_type =
+            self.types.iter_mut().find(|t| t.is_enum() && t.name() == name).ok_or_else(|| {
+                Error::Other(Cow::Owned(format!("`{name}` is not an enum of the contract")))
+            })?;
+        if variants.is_empty() || variants.len() > 256 {
+            return Err(Error::Other(Cow::Owned(format!("`{name}` must have 1 to 256 members"))));
+        }
+        user_type.set_variants(variants);
+        Ok(())
+    }
+
     /// Generates rust interface for a contract.
     pub fn generate(&self) -> TokenStream {
         let constructor = self.constructor.as_ref().map(Constructor::generate);
         let functions: Vec<_> = self.functions.iter().map(Function::generate).collect();
         let events: Vec<_> = self.events.iter().map(Event::generate_event).collect();
         let logs: Vec<_> = self.events.iter().map(Event::generate_log).collect();
+        let types = (!self.types.is_empty()).then(|| {
+            let types: Vec<_> = self.types.iter().map(UserType::generate).collect();
+            let clashes = &self.clashes;
+            quote! {
+                /// Contract's enums and user-defined value types.
+                pub mod types {
+                    use rethabi;
+                    #(compile_error!(#clashes);)*
+                    #(#types)*
+                }
+            }
+        });
+        let [a, b, c, d] = self.interface_id;
         quote! {
             use rethabi;
             const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
 
+            /// Contract's ERC-165 interface identifier, the XOR of its function selectors.
+            pub const INTERFACE_ID: [u8; 4] = [#a, #b, #c, #d];
+
+            #types
+
             #constructor
 
             /// Contract's functions.
@@ -76,8 +141,9 @@ mod test {
             functions: Default::default(),
             events: Default::default(),
             errors: Default::default(),
-            receive: false,
-            fallback: false,
+            receive: None,
+            fallback: None,
+            items: vec![],
         };
 
         let c = Contract::from(&ethabi_contract);
@@ -86,6 +152,9 @@ mod test {
             use rethabi;
             const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
 
+            /// Contract's ERC-165 interface identifier, the XOR of its function selectors.
+            pub const INTERFACE_ID: [u8; 4] = [0u8, 0u8, 0u8, 0u8];
+
             /// Contract's functions.
             pub mod functions {
                 use super::INTERNAL_ERR;
@@ -105,4 +174,30 @@ mod test {
 
         assert_eq!(expected.to_string(), c.generate().to_string());
     }
+
+    #[test]
+    fn test_type_clash() {
+        let param = |internal_type: &str| rethabi::Param {
+            name: "side".into(),
+            kind: rethabi::ParamType::Uint(8),
+            internal_type: Some(internal_type.into()),
+            components: vec![],
+        };
+        let mut ethabi_contract = rethabi::Contract::default();
+        for (name, internal_type) in [("buy", "enum Market.Side"), ("sell", "enum Auction.Side")] {
+            ethabi_contract.add_function(rethabi::Function::new(
+                name,
+                vec![param(internal_type)],
+                vec![],
+                rethabi::StateMutability::NonPayable,
+            ));
+        }
+
+        let c = Contract::from(&ethabi_contract);
+
+        let expected = quote! {
+            compile_error!("`enum Market.Side` and `enum Auction.Side` both generate the type `Side`");
+        };
+        assert!(c.generate().to_string().contains(&expected.to_string()));
+    }
 }
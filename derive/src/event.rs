@@ -10,7 +10,10 @@ use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use super::{from_token, get_template_names, rust_type, to_syntax_string, to_token};
+use super::{
+    get_template_names, to_syntax_string, to_token,
+    types::{param_from_token, param_rust_type, param_to_token, UserType},
+};
 
 /// Structure used to generate contract's event interface.
 pub struct Event {
@@ -27,6 +30,11 @@ pub struct Event {
 
 impl<'a> From<&'a rethabi::Event> for Event {
     fn from(e: &'a rethabi::Event) -> Self {
+        // Generated enums and user-defined value types live next to the `events` and `logs`
+        // modules.
+        let types = quote! { super::super::types };
+        let log_types = quote! { super::types };
+
         let names: Vec<_> = e
             .inputs
             .iter()
@@ -43,7 +51,8 @@ impl<'a> From<&'a rethabi::Event> for Event {
                 }
             })
             .collect();
-        let kinds: Vec<_> = e.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+        let kinds: Vec<_> =
+            e.inputs.iter().map(|param| param_rust_type(param, &log_types)).collect();
         let log_fields = names
             .iter()
             .zip(kinds.iter())
@@ -53,7 +62,7 @@ impl<'a> From<&'a rethabi::Event> for Event {
         let log_iter = quote! { log.next().expect(INTERNAL_ERR).value };
 
         let to_log: Vec<_> =
-            e.inputs.iter().map(|param| from_token(&param.kind, &log_iter)).collect();
+            e.inputs.iter().map(|param| param_from_token(param, &log_iter, &types)).collect();
 
         let log_init = names
             .iter()
@@ -65,7 +74,7 @@ impl<'a> From<&'a rethabi::Event> for Event {
             .inputs
             .iter()
             .filter(|param| param.indexed)
-            .map(|param| rust_type(&param.kind))
+            .map(|param| param_rust_type(param, &types))
             .collect();
         let topic_names: Vec<_> = e
             .inputs
@@ -107,8 +116,11 @@ impl<'a> From<&'a rethabi::Event> for Event {
             .take(3)
             .map(|(index, (param_name, param))| {
                 let topic = syn::Ident::new(&format!("topic{index}"), Span::call_site());
-                let i = quote! { i };
-                let to_token = to_token(&i, &param.kind);
+                let i = syn::Ident::new("i", Span::call_site());
+                let to_token = match UserType::from_param(param) {
+                    Some(_) => param_to_token(param, &i, &types),
+                    None => to_token(&quote! { #i }, &param.kind),
+                };
                 quote! { #topic: #param_name.into().map(|#i| #to_token), }
             })
             .collect();
@@ -120,13 +132,12 @@ impl<'a> From<&'a rethabi::Event> for Event {
                 let name = &x.name;
                 let kind = to_syntax_string(&x.kind);
                 let indexed = x.indexed;
-
-                quote! {
-                    rethabi::EventParam {
-                        name: #name.to_owned(),
-                        kind: #kind,
-                        indexed: #indexed
-                    }
+                let param = quote! { rethabi::EventParam::new(#name, #kind, #indexed) };
+                match &x.internal_type {
+                    Some(internal_type) => quote! {
+                        rethabi::EventParam { internal_type: Some(#internal_type.to_owned()), ..#param }
+                    },
+                    None => param,
                 }
             })
             .collect::<Vec<_>>();
@@ -283,11 +294,7 @@ mod tests {
     fn test_event_with_one_input() {
         let ethabi_event = rethabi::Event {
             name: "one".into(),
-            inputs: vec![rethabi::EventParam {
-                name: "foo".into(),
-                kind: rethabi::ParamType::Address,
-                indexed: true,
-            }],
+            inputs: vec![rethabi::EventParam::new("foo", rethabi::ParamType::Address, true)],
             anonymous: false,
         };
 
@@ -301,11 +308,7 @@ mod tests {
                 pub fn event() -> rethabi::Event {
                     rethabi::Event {
                         name: "One".into(),
-                        inputs: vec![rethabi::EventParam {
-                            name: "foo".to_owned(),
-                            kind: rethabi::ParamType::Address,
-                            indexed: true
-                        }],
+                        inputs: vec![rethabi::EventParam::new("foo", rethabi::ParamType::Address, true)],
                         anonymous: false,
                     }
                 }
@@ -343,11 +346,7 @@ mod tests {
     fn test_log_with_one_field() {
         let ethabi_event = rethabi::Event {
             name: "one".into(),
-            inputs: vec![rethabi::EventParam {
-                name: "foo".into(),
-                kind: rethabi::ParamType::Address,
-                indexed: false,
-            }],
+            inputs: vec![rethabi::EventParam::new("foo", rethabi::ParamType::Address, false)],
             anonymous: false,
         };
 
@@ -369,21 +368,13 @@ mod tests {
         let ethabi_event = rethabi::Event {
             name: "many".into(),
             inputs: vec![
-                rethabi::EventParam {
-                    name: "foo".into(),
-                    kind: rethabi::ParamType::Address,
-                    indexed: false,
-                },
-                rethabi::EventParam {
-                    name: "bar".into(),
-                    kind: rethabi::ParamType::Array(Box::new(rethabi::ParamType::String)),
-                    indexed: false,
-                },
-                rethabi::EventParam {
-                    name: "xyz".into(),
-                    kind: rethabi::ParamType::Uint(256),
-                    indexed: false,
-                },
+                rethabi::EventParam::new("foo", rethabi::ParamType::Address, false),
+                rethabi::EventParam::new(
+                    "bar",
+                    rethabi::ParamType::Array(Box::new(rethabi::ParamType::String)),
+                    false,
+                ),
+                rethabi::EventParam::new("xyz", rethabi::ParamType::Uint(256), false),
             ],
             anonymous: false,
         };
@@ -11,8 +11,8 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
 use super::{
-    from_template_param, from_token, get_output_kinds, get_template_names, input_names, rust_type,
-    template_param_type, to_ethabi_param_vec, to_token,
+    get_output_kinds, get_template_names, input_names, to_ethabi_param_vec,
+    types::{param_from_token, param_rust_type, param_template_type, param_to_token},
 };
 
 struct TemplateParam {
@@ -70,6 +70,9 @@ pub struct Function {
 
 impl<'a> From<&'a rethabi::Function> for Function {
     fn from(f: &'a rethabi::Function) -> Self {
+        // Generated enums and user-defined value types live next to the `functions` module.
+        let types = quote! { super::super::types };
+
         // [param0, hello_world, param2]
         let input_names = input_names(&f.inputs);
 
@@ -78,10 +81,10 @@ impl<'a> From<&'a rethabi::Function> for Function {
             .inputs
             .iter()
             .enumerate()
-            .map(|(index, param)| template_param_type(&param.kind, index));
+            .map(|(index, param)| param_template_type(param, index, &types));
 
         // [Uint, Bytes, Vec<Uint>]
-        let kinds: Vec<_> = f.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+        let kinds: Vec<_> = f.inputs.iter().map(|param| param_rust_type(param, &types)).collect();
 
         // [T0, T1, T2]
         let template_names: Vec<_> = get_template_names(&kinds);
@@ -102,12 +105,10 @@ impl<'a> From<&'a rethabi::Function> for Function {
         let tokenize: Vec<_> = input_names
             .iter()
             .zip(f.inputs.iter())
-            .map(|(param_name, param)| {
-                to_token(&from_template_param(&param.kind, param_name), &param.kind)
-            })
+            .map(|(param_name, param)| param_to_token(param, param_name, &types))
             .collect();
 
-        let output_result = get_output_kinds(&f.outputs);
+        let output_result = get_output_kinds(&f.outputs, &types);
 
         let output_implementation = match f.outputs.len() {
             0 => quote! {
@@ -116,7 +117,7 @@ impl<'a> From<&'a rethabi::Function> for Function {
             },
             1 => {
                 let o = quote! { out };
-                let from_first = from_token(&f.outputs[0].kind, &o);
+                let from_first = param_from_token(&f.outputs[0], &o, &types);
                 quote! {
                     let out = self.0.decode_output(output)?.into_iter().next().expect(INTERNAL_ERR);
                     Ok(#from_first)
@@ -125,7 +126,7 @@ impl<'a> From<&'a rethabi::Function> for Function {
             _ => {
                 let o = quote! { out.next().expect(INTERNAL_ERR) };
                 let outs: Vec<_> =
-                    f.outputs.iter().map(|param| from_token(&param.kind, &o)).collect();
+                    f.outputs.iter().map(|param| param_from_token(param, &o, &types)).collect();
 
                 quote! {
                     let mut out = self.0.decode_output(output)?.into_iter();
@@ -305,16 +306,8 @@ mod tests {
         #[allow(deprecated)]
         let ethabi_function = rethabi::Function {
             name: "hello".into(),
-            inputs: vec![rethabi::Param {
-                name: "foo".into(),
-                kind: rethabi::ParamType::Address,
-                internal_type: None,
-            }],
-            outputs: vec![rethabi::Param {
-                name: "bar".into(),
-                kind: rethabi::ParamType::Uint(256),
-                internal_type: None,
-            }],
+            inputs: vec![rethabi::Param::new("foo", rethabi::ParamType::Address)],
+            outputs: vec![rethabi::Param::new("bar", rethabi::ParamType::Uint(256))],
             constant: None,
             state_mutability: rethabi::StateMutability::Payable,
         };
@@ -329,16 +322,8 @@ mod tests {
                 fn function() -> rethabi::Function {
                     rethabi::Function {
                         name: "hello".into(),
-                        inputs: vec![rethabi::Param {
-                            name: "foo".to_owned(),
-                            kind: rethabi::ParamType::Address,
-                            internal_type: None
-                        }],
-                        outputs: vec![rethabi::Param {
-                            name: "bar".to_owned(),
-                            kind: rethabi::ParamType::Uint(256usize),
-                            internal_type: None
-                        }],
+                        inputs: vec![rethabi::Param::new("foo", rethabi::ParamType::Address)],
+                        outputs: vec![rethabi::Param::new("bar", rethabi::ParamType::Uint(256usize))],
                         constant: Some(false),
                         state_mutability: ::rethabi::StateMutability::Payable
                     }
@@ -387,28 +372,18 @@ mod tests {
         let ethabi_function = rethabi::Function {
             name: "multi".into(),
             inputs: vec![
-                rethabi::Param {
-                    name: "foo".into(),
-                    kind: rethabi::ParamType::FixedArray(Box::new(rethabi::ParamType::Address), 2),
-                    internal_type: None,
-                },
-                rethabi::Param {
-                    name: "bar".into(),
-                    kind: rethabi::ParamType::Array(Box::new(rethabi::ParamType::Uint(256))),
-                    internal_type: None,
-                },
+                rethabi::Param::new(
+                    "foo",
+                    rethabi::ParamType::FixedArray(Box::new(rethabi::ParamType::Address), 2),
+                ),
+                rethabi::Param::new(
+                    "bar",
+                    rethabi::ParamType::Array(Box::new(rethabi::ParamType::Uint(256))),
+                ),
             ],
             outputs: vec![
-                rethabi::Param {
-                    name: "".into(),
-                    kind: rethabi::ParamType::Uint(256),
-                    internal_type: None,
-                },
-                rethabi::Param {
-                    name: "".into(),
-                    kind: rethabi::ParamType::String,
-                    internal_type: None,
-                },
+                rethabi::Param::new("", rethabi::ParamType::Uint(256)),
+                rethabi::Param::new("", rethabi::ParamType::String),
             ],
             constant: None,
             state_mutability: rethabi::StateMutability::Payable,
@@ -424,24 +399,8 @@ mod tests {
                 fn function() -> rethabi::Function {
                     rethabi::Function {
                         name: "multi".into(),
-                        inputs: vec![rethabi::Param {
-                            name: "foo".to_owned(),
-                            kind: rethabi::ParamType::FixedArray(Box::new(rethabi::ParamType::Address), 2usize),
-                            internal_type: None
-                        }, rethabi::Param {
-                            name: "bar".to_owned(),
-                            kind: rethabi::ParamType::Array(Box::new(rethabi::ParamType::Uint(256usize))),
-                            internal_type: None
-                        }],
-                        outputs: vec![rethabi::Param {
-                            name: "".to_owned(),
-                            kind: rethabi::ParamType::Uint(256usize),
-                            internal_type: None
-                        }, rethabi::Param {
-                            name: "".to_owned(),
-                            kind: rethabi::ParamType::String,
-                            internal_type: None
-                        }],
+                        inputs: vec![rethabi::Param::new("foo", rethabi::ParamType::FixedArray(Box::new(rethabi::ParamType::Address), 2usize)), rethabi::Param::new("bar", rethabi::ParamType::Array(Box::new(rethabi::ParamType::Uint(256usize))))],
+                        outputs: vec![rethabi::Param::new("", rethabi::ParamType::Uint(256usize)), rethabi::Param::new("", rethabi::ParamType::String)],
                         constant: Some(false),
                         state_mutability: ::rethabi::StateMutability::Payable
                     }
@@ -14,6 +14,7 @@ mod constructor;
 mod contract;
 mod event;
 mod function;
+mod types;
 
 use heck::ToSnakeCase;
 use proc_macro2::Span;
@@ -41,7 +42,10 @@ fn impl_ethabi_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream
         )))
     })?;
     let contract = Contract::load(source_file)?;
-    let c = contract::Contract::from(&contract);
+    let mut c = contract::Contract::from(&contract);
+    for (name, variants) in get_enums(&options)? {
+        c.declare_enum(&name, variants)?;
+    }
     Ok(c.generate())
 }
 
@@ -68,6 +72,37 @@ fn get_option(options: &[syn::NestedMeta], name: &str) -> Result<String> {
     str_value_of_meta_item(item, name)
 }
 
+/// Returns the enum members declared in the form `enums(Side(Buy, Sell), ...)`.
+fn get_enums(options: &[syn::NestedMeta]) -> Result<Vec<(syn::Ident, Vec<syn::Ident>)>> {
+    let list = options.iter().find_map(|nested| match *nested {
+        syn::NestedMeta::Meta(syn::Meta::List(ref list)) if list.path.is_ident("enums") => {
+            Some(list)
+        }
+        _ => None,
+    });
+    let list = match list {
+        Some(list) => list,
+        None => return Ok(vec![]),
+    };
+
+    let ident = |nested: &syn::NestedMeta| match *nested {
+        syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => path.get_ident().cloned(),
+        _ => None,
+    };
+    list.nested
+        .iter()
+        .map(|nested| match *nested {
+            syn::NestedMeta::Meta(syn::Meta::List(ref list)) => {
+                let name = list.path.get_ident().cloned();
+                let variants = list.nested.iter().map(ident).collect::<Option<Vec<_>>>();
+                name.zip(variants)
+            }
+            _ => None,
+        })
+        .collect::<Option<_>>()
+        .ok_or(Error::Other(Cow::Borrowed("`enums` must be in the form `enums(Side(Buy, Sell))`")))
+}
+
 fn str_value_of_meta_item(item: &syn::Meta, name: &str) -> Result<String> {
     if let syn::Meta::NameValue(ref name_value) = *item {
         if let syn::Lit::Str(ref value) = name_value.lit {
@@ -95,8 +130,11 @@ fn to_syntax_string(param_type: &rethabi::ParamType) -> proc_macro2::TokenStream
         ParamType::Bytes => quote! { rethabi::ParamType::Bytes },
         ParamType::Int(x) => quote! { rethabi::ParamType::Int(#x) },
         ParamType::Uint(x) => quote! { rethabi::ParamType::Uint(#x) },
+        ParamType::Fixed(x, y) => quote! { rethabi::ParamType::Fixed(#x, #y) },
+        ParamType::Ufixed(x, y) => quote! { rethabi::ParamType::Ufixed(#x, #y) },
         ParamType::Bool => quote! { rethabi::ParamType::Bool },
         ParamType::String => quote! { rethabi::ParamType::String },
+        ParamType::Function => quote! { rethabi::ParamType::Function },
         ParamType::Array(ref param_type) => {
             let param_type_quote = to_syntax_string(param_type);
             quote! { rethabi::ParamType::Array(Box::new(#param_type_quote)) }
@@ -114,22 +152,16 @@ fn to_syntax_string(param_type: &rethabi::ParamType) -> proc_macro2::TokenStream
     }
 }
 
-fn to_ethabi_param_vec<'a, P: 'a>(params: P) -> proc_macro2::TokenStream
+fn to_ethabi_param_vec<'a, P>(params: P) -> proc_macro2::TokenStream
 where
-    P: IntoIterator<Item = &'a Param>,
+    P: IntoIterator<Item = &'a Param> + 'a,
 {
     let p = params
         .into_iter()
         .map(|x| {
             let name = &x.name;
             let kind = to_syntax_string(&x.kind);
-            quote! {
-                rethabi::Param {
-                    name: #name.to_owned(),
-                    kind: #kind,
-                    internal_type: None
-                }
-            }
+            quote! { rethabi::Param::new(#name, #kind) }
         })
         .collect::<Vec<_>>();
 
@@ -142,10 +174,11 @@ fn rust_type(input: &ParamType) -> proc_macro2::TokenStream {
         ParamType::Bytes => quote! { ::rethabi::Bytes },
         ParamType::FixedBytes(32) => quote! { ::rethabi::Hash },
         ParamType::FixedBytes(size) => quote! { [u8; #size] },
-        ParamType::Int(_) => quote! { ::rethabi::Int },
-        ParamType::Uint(_) => quote! { ::rethabi::Uint },
+        ParamType::Int(_) | ParamType::Fixed(..) => quote! { ::rethabi::Int },
+        ParamType::Uint(_) | ParamType::Ufixed(..) => quote! { ::rethabi::Uint },
         ParamType::Bool => quote! { bool },
         ParamType::String => quote! { String },
+        ParamType::Function => quote! { (::rethabi::Address, [u8; 4]) },
         ParamType::Array(ref kind) => {
             let t = rust_type(kind);
             quote! { Vec<#t> }
@@ -190,7 +223,7 @@ fn template_param_type(input: &ParamType, index: usize) -> proc_macro2::TokenStr
 
 fn _template_param_type(input: &ParamType, ident: &syn::Ident) -> proc_macro2::TokenStream {
     match input {
-        ParamType::Int(_) | ParamType::Uint(_) => {
+        ParamType::Int(_) | ParamType::Uint(_) | ParamType::Fixed(..) | ParamType::Ufixed(..) => {
             quote! { #ident: ::rethabi::ruint::UintTryTo<::rethabi::Uint> }
         }
         ParamType::Array(_) | ParamType::FixedArray(_, _) => {
@@ -220,7 +253,7 @@ fn from_template_param(input: &ParamType, name: &syn::Ident) -> proc_macro2::Tok
             let convert = from_template_param(ty.as_ref(), &arg);
             quote! { #name.into().into_iter().map(|#arg| #convert).collect::<Vec<_>>() }
         }
-        ParamType::Uint(_) | ParamType::Int(_) => {
+        ParamType::Uint(_) | ParamType::Int(_) | ParamType::Fixed(..) | ParamType::Ufixed(..) => {
             quote! { #name.uint_try_to().expect(INTERNAL_ERR) }
         }
         _ => quote! { #name.into() },
@@ -234,8 +267,16 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
         ParamType::FixedBytes(_) => quote! { rethabi::Token::FixedBytes(#name.to_vec()) },
         ParamType::Int(_) => quote! { rethabi::Token::Int(#name) },
         ParamType::Uint(_) => quote! { rethabi::Token::Uint(#name) },
+        ParamType::Fixed(_, decimals) => quote! { rethabi::Token::Fixed(#name, #decimals) },
+        ParamType::Ufixed(_, decimals) => quote! { rethabi::Token::Ufixed(#name, #decimals) },
         ParamType::Bool => quote! { rethabi::Token::Bool(#name) },
         ParamType::String => quote! { rethabi::Token::String(#name) },
+        ParamType::Function => quote! {
+            {
+                let (address, selector) = #name;
+                rethabi::Token::Function(address, selector)
+            }
+        },
         ParamType::Array(ref kind) => {
             let inner_name = quote! { inner };
             let inner_loop = to_token(&inner_name, kind);
@@ -290,8 +331,11 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
         }
         ParamType::Int(_) => quote! { #token.into_int().expect(INTERNAL_ERR) },
         ParamType::Uint(_) => quote! { #token.into_uint().expect(INTERNAL_ERR) },
+        ParamType::Fixed(..) => quote! { #token.into_fixed().expect(INTERNAL_ERR).0 },
+        ParamType::Ufixed(..) => quote! { #token.into_ufixed().expect(INTERNAL_ERR).0 },
         ParamType::Bool => quote! { #token.into_bool().expect(INTERNAL_ERR) },
         ParamType::String => quote! { #token.into_string().expect(INTERNAL_ERR) },
+        ParamType::Function => quote! { #token.into_function().expect(INTERNAL_ERR) },
         ParamType::Array(ref kind) => {
             let inner = quote! { inner };
             let inner_loop = from_token(kind, &inner);
@@ -343,15 +387,19 @@ fn get_template_names(kinds: &[proc_macro2::TokenStream]) -> Vec<syn::Ident> {
         .collect()
 }
 
-fn get_output_kinds(outputs: &[Param]) -> proc_macro2::TokenStream {
+fn get_output_kinds(
+    outputs: &[Param],
+    types: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
     match outputs.len() {
         0 => quote! {()},
         1 => {
-            let t = rust_type(&outputs[0].kind);
+            let t = types::param_rust_type(&outputs[0], types);
             quote! { #t }
         }
         _ => {
-            let outs: Vec<_> = outputs.iter().map(|param| rust_type(&param.kind)).collect();
+            let outs: Vec<_> =
+                outputs.iter().map(|param| types::param_rust_type(param, types)).collect();
             quote! { (#(#outs),*) }
         }
     }
@@ -0,0 +1,365 @@
+// Copyright 2015-2019 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use rethabi::{EventParam, InternalType, Param, ParamType};
+
+use super::{
+    from_template_param, from_token, rust_type, template_param_type, to_syntax_string, to_token,
+};
+
+/// Param whose Solidity type may be declared in `internalType`.
+pub trait DeclaredParam {
+    /// Type the value is encoded as.
+    fn kind(&self) -> &ParamType;
+    /// Solidity type the param was declared with, if known.
+    fn internal_type(&self) -> Option<InternalType>;
+}
+
+impl DeclaredParam for Param {
+    fn kind(&self) -> &ParamType {
+        &self.kind
+    }
+
+    fn internal_type(&self) -> Option<InternalType> {
+        self.parse_internal_type()
+    }
+}
+
+impl DeclaredParam for EventParam {
+    fn kind(&self) -> &ParamType {
+        &self.kind
+    }
+
+    fn internal_type(&self) -> Option<InternalType> {
+        self.parse_internal_type()
+    }
+}
+
+/// Structure used to generate a rust type for a solidity enum or user-defined value type.
+pub struct UserType {
+    /// Type name.
+    name: syn::Ident,
+    /// Solidity declaration of the type, as found in `internalType`.
+    internal_type: String,
+    /// Type the value is encoded as.
+    kind: ParamType,
+    /// Whether the type is an enum.
+    is_enum: bool,
+    /// Names of the enum members, in declaration order, if declared in the derive options.
+    variants: Option<Vec<syn::Ident>>,
+}
+
+impl UserType {
+    /// Returns the type generated for the param, if it is declared with an enum
+    /// or a user-defined value type.
+    pub fn from_param<P: DeclaredParam>(param: &P) -> Option<Self> {
+        let internal_type = param.internal_type()?;
+        if internal_type.is_array() {
+            return None;
+        }
+        let is_enum = match (&internal_type, param.kind()) {
+            (InternalType::Enum { .. }, ParamType::Uint(_)) => true,
+            (
+                InternalType::UserDefined { .. },
+                ParamType::Bytes
+                | ParamType::String
+                | ParamType::Array(_)
+                | ParamType::FixedArray(..)
+                | ParamType::Tuple(_),
+            ) => return None,
+            (InternalType::UserDefined { .. }, _) => false,
+            _ => return None,
+        };
+        Some(UserType {
+            name: syn::Ident::new(&internal_type.ty().to_upper_camel_case(), Span::call_site()),
+            internal_type: internal_type.to_string(),
+            kind: param.kind().clone(),
+            is_enum,
+            variants: None,
+        })
+    }
+
+    /// Returns the type name.
+    pub fn name(&self) -> &syn::Ident {
+        &self.name
+    }
+
+    /// Returns the Solidity declaration of the type.
+    pub fn internal_type(&self) -> &str {
+        &self.internal_type
+    }
+
+    /// Returns whether both types are generated from the same Solidity type.
+    pub fn is_same(&self, other: &UserType) -> bool {
+        self.internal_type == other.internal_type && self.kind == other.kind
+    }
+
+    /// Returns whether the type is an enum.
+    pub fn is_enum(&self) -> bool {
+        self.is_enum
+    }
+
+    /// Sets the names of the enum members, which the ABI does not carry.
+    pub fn set_variants(&mut self, variants: Vec<syn::Ident>) {
+        self.variants = Some(variants);
+    }
+
+    /// Generates the rust type.
+    pub fn generate(&self) -> TokenStream {
+        if self.is_enum {
+            self.generate_enum()
+        } else {
+            self.generate_user_defined()
+        }
+    }
+
+    fn generate_enum(&self) -> TokenStream {
+        let name = &self.name;
+        let (declaration, to_index, from_index) = match &self.variants {
+            Some(variants) => {
+                let doc = format!(
+                    "Solidity `{}`.\n\nConversions from `u8`, `rethabi::Uint` and decoded tokens \
+                     fail if the index is not one of a declared member.",
+                    self.internal_type
+                );
+                let indices = (0..variants.len()).map(|index| index as u8);
+                let declaration = quote! {
+                    #[doc = #doc]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                    #[repr(u8)]
+                    pub enum #name {
+                        #(#variants),*
+                    }
+
+                    impl From<#name> for u8 {
+                        fn from(value: #name) -> Self {
+                            value as u8
+                        }
+                    }
+
+                    impl ::core::convert::TryFrom<u8> for #name {
+                        type Error = rethabi::Error;
+
+                        fn try_from(index: u8) -> rethabi::Result<Self> {
+                            match index {
+                                #(#indices => Ok(#name::#variants),)*
+                                _ => Err(rethabi::Error::InvalidData),
+                            }
+                        }
+                    }
+                };
+                (
+                    declaration,
+                    quote! { value as u8 },
+                    quote! { <#name as ::core::convert::TryFrom<u8>>::try_from(index) },
+                )
+            }
+            None => {
+                let doc = format!(
+                    "Solidity `{}`.\n\nHolds the index of the enum member. The ABI does not carry the \
+                     names or number of the members, declare them with the `enums` derive option to \
+                     get a rust enum. Until then conversions from `rethabi::Uint` and decoded tokens \
+                     only fail if the index does not fit in `uint8`.",
+                    self.internal_type
+                );
+                let declaration = quote! {
+                    #[doc = #doc]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                    pub struct #name(pub u8);
+
+                    impl From<u8> for #name {
+                        fn from(index: u8) -> Self {
+                            #name(index)
+                        }
+                    }
+                };
+                (declaration, quote! { value.0 }, quote! { Ok(#name(index)) })
+            }
+        };
+
+        quote! {
+            #declaration
+
+            impl From<#name> for rethabi::Uint {
+                fn from(value: #name) -> Self {
+                    rethabi::Uint::from(#to_index)
+                }
+            }
+
+            impl From<#name> for rethabi::Token {
+                fn from(value: #name) -> Self {
+                    rethabi::Token::Uint(value.into())
+                }
+            }
+
+            impl ::core::convert::TryFrom<rethabi::Uint> for #name {
+                type Error = rethabi::Error;
+
+                fn try_from(value: rethabi::Uint) -> rethabi::Result<Self> {
+                    let index = <u8 as ::core::convert::TryFrom<rethabi::Uint>>::try_from(value)
+                        .map_err(|_| rethabi::Error::InvalidData)?;
+                    #from_index
+                }
+            }
+
+            impl ::core::convert::TryFrom<rethabi::Token> for #name {
+                type Error = rethabi::Error;
+
+                fn try_from(token: rethabi::Token) -> rethabi::Result<Self> {
+                    let value = token.into_uint().ok_or(rethabi::Error::InvalidData)?;
+                    <Self as ::core::convert::TryFrom<rethabi::Uint>>::try_from(value)
+                }
+            }
+        }
+    }
+
+    fn generate_user_defined(&self) -> TokenStream {
+        let name = &self.name;
+        let doc = format!("Solidity user-defined value type `{}`.", self.internal_type);
+        let inner = rust_type(&self.kind);
+        let kind = to_syntax_string(&self.kind);
+        let to_token = to_token(&quote! { value.0 }, &self.kind);
+        let from_token = from_token(&self.kind, &quote! { token });
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct #name(pub #inner);
+
+            impl From<#inner> for #name {
+                fn from(value: #inner) -> Self {
+                    #name(value)
+                }
+            }
+
+            impl From<#name> for #inner {
+                fn from(value: #name) -> Self {
+                    value.0
+                }
+            }
+
+            impl From<#name> for rethabi::Token {
+                fn from(value: #name) -> Self {
+                    #to_token
+                }
+            }
+
+            impl ::core::convert::TryFrom<rethabi::Token> for #name {
+                type Error = rethabi::Error;
+
+                fn try_from(token: rethabi::Token) -> rethabi::Result<Self> {
+                    use super::INTERNAL_ERR;
+                    if !token.type_check(&#kind) {
+                        return Err(rethabi::Error::InvalidData);
+                    }
+                    Ok(#name(#from_token))
+                }
+            }
+        }
+    }
+}
+
+/// Returns the rust type of the param, as seen from the module `types` is accessible at.
+pub fn param_rust_type<P: DeclaredParam>(param: &P, types: &TokenStream) -> TokenStream {
+    match UserType::from_param(param) {
+        Some(user_type) => {
+            let name = user_type.name();
+            quote! { #types::#name }
+        }
+        None => rust_type(param.kind()),
+    }
+}
+
+/// Returns the template param declaration of the input param.
+pub fn param_template_type<P: DeclaredParam>(
+    param: &P,
+    index: usize,
+    types: &TokenStream,
+) -> TokenStream {
+    match UserType::from_param(param) {
+        Some(user_type) => {
+            let t_ident = syn::Ident::new(&format!("T{index}"), Span::call_site());
+            let name = user_type.name();
+            quote! { #t_ident: ::core::convert::Into<#types::#name> }
+        }
+        None => template_param_type(param.kind(), index),
+    }
+}
+
+/// Converts the template param of the input param into a token.
+pub fn param_to_token<P: DeclaredParam>(
+    param: &P,
+    name: &syn::Ident,
+    types: &TokenStream,
+) -> TokenStream {
+    match UserType::from_param(param) {
+        Some(user_type) => {
+            let type_name = user_type.name();
+            quote! {
+                rethabi::Token::from(::core::convert::Into::<#types::#type_name>::into(#name))
+            }
+        }
+        None => to_token(&from_template_param(param.kind(), name), param.kind()),
+    }
+}
+
+/// Converts a decoded token of the output param into its rust type.
+///
+/// Conversion into a generated type may fail, so this must be used in a function returning
+/// `rethabi::Result`.
+pub fn param_from_token<P: DeclaredParam>(
+    param: &P,
+    token: &TokenStream,
+    types: &TokenStream,
+) -> TokenStream {
+    match UserType::from_param(param) {
+        Some(user_type) => {
+            let name = user_type.name();
+            quote! { <#types::#name as ::core::convert::TryFrom<rethabi::Token>>::try_from(#token)? }
+        }
+        None => from_token(param.kind(), token),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserType;
+
+    fn param(kind: rethabi::ParamType, internal_type: &str) -> rethabi::Param {
+        rethabi::Param {
+            name: "foo".into(),
+            kind,
+            internal_type: Some(internal_type.into()),
+            components: vec![],
+        }
+    }
+
+    #[test]
+    fn test_user_type_from_param() {
+        use rethabi::ParamType;
+
+        let side = UserType::from_param(&param(ParamType::Uint(8), "enum Market.Side")).unwrap();
+        assert_eq!(side.name(), "Side");
+        assert!(side.is_enum);
+
+        let price = UserType::from_param(&param(ParamType::Uint(128), "Market.Price")).unwrap();
+        assert_eq!(price.name(), "Price");
+        assert!(!price.is_enum);
+
+        assert!(UserType::from_param(&param(ParamType::Uint(256), "uint256")).is_none());
+        assert!(UserType::from_param(&param(ParamType::Address, "contract IERC20")).is_none());
+        assert!(UserType::from_param(&param(
+            ParamType::Array(Box::new(ParamType::Uint(8))),
+            "enum Market.Side[]"
+        ))
+        .is_none());
+    }
+}
@@ -14,6 +14,14 @@ use_contract!(validators, "../res/Validators.abi");
 use_contract!(operations, "../res/Operations.abi");
 use_contract!(urlhint, "../res/urlhint.abi");
 use_contract!(test_rust_keywords, "../res/test_rust_keywords.abi");
+use_contract!(callback, "../res/callback.abi");
+use_contract!(market_indices, "../res/market.abi");
+
+mod market {
+    #[derive(rethabi_derive::EthabiContract)]
+    #[ethabi_contract_options(path = "../res/market.abi", enums(Side(Buy, Sell)))]
+    struct _Dummy;
+}
 
 use hex_literal::hex;
 use rethabi::{Address, Uint};
@@ -27,6 +35,7 @@ impl From<Wrapper> for Address {
 }
 
 #[test]
+#[allow(clippy::useless_conversion)]
 fn test_encoding_function_input_as_array() {
     use validators::functions;
 
@@ -45,6 +54,12 @@ fn test_encoding_function_input_as_array() {
     assert_eq!(expected, hex::encode(encoded_from_vec_wrapped));
 }
 
+#[test]
+fn test_interface_id() {
+    // ERC-20
+    assert_eq!(eip20::INTERFACE_ID, hex!("36372b07"));
+}
+
 #[test]
 fn test_decoding_function_output() {
     // Make sure that the output param type of the derived contract is correct
@@ -63,6 +78,7 @@ fn test_decoding_function_output() {
 }
 
 #[test]
+#[allow(clippy::useless_conversion)]
 fn test_encoding_constructor_as_array() {
     use validators::constructor;
 
@@ -116,3 +132,89 @@ fn encoding_input_works() {
     let wildcard_filter_sugared = eip20::events::transfer::wildcard_filter();
     assert_eq!(wildcard_filter, wildcard_filter_sugared);
 }
+
+#[test]
+fn test_function_pointer_param() {
+    use callback::functions;
+
+    let callback = (Address::from([0x11u8; 20]), [0x12, 0x34, 0x56, 0x78]);
+
+    let encoded = functions::schedule::encode_input(callback, Uint::from(10));
+    let expected = "bf58e11c\
+		1111111111111111111111111111111111111111123456780000000000000000\
+		000000000000000000000000000000000000000000000000000000000000000a";
+    assert_eq!(hex::encode(encoded), expected);
+
+    let output = hex!("1111111111111111111111111111111111111111123456780000000000000000");
+    assert_eq!(functions::schedule::decode_output(&output).unwrap(), callback);
+}
+
+#[test]
+fn test_enum_and_user_defined_value_types() {
+    use market::{events, functions, logs, types};
+
+    let encoded = functions::place::encode_input(types::Side::Sell, Uint::from(5), Uint::from(7));
+    let expected = "00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000005";
+    assert_eq!(&hex::encode(&encoded)[8..136], expected);
+
+    let output = hex!(
+        "
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000005
+	"
+    );
+    let (side, price) = functions::place::decode_output(&output).unwrap();
+    assert_eq!(side, types::Side::Sell);
+    assert_eq!(price, types::Price(Uint::from(5)));
+    assert_eq!(types::Side::try_from(0).unwrap(), types::Side::Buy);
+    assert!(types::Side::try_from(2).is_err());
+
+    // index of no declared member
+    let output = hex!(
+        "
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000005
+	"
+    );
+    assert!(functions::place::decode_output(&output).is_err());
+    let (side, _) = market_indices::functions::place::decode_output(&output).unwrap();
+    assert_eq!(side, market_indices::types::Side(2));
+
+    // enum member index out of `uint8` range
+    let output = hex!(
+        "
+		0000000000000000000000000000000000000000000000000000000000000100
+		0000000000000000000000000000000000000000000000000000000000000005
+	"
+    );
+    assert!(market_indices::functions::place::decode_output(&output).is_err());
+
+    let encoded = market::constructor(vec![0x60], types::Price(Uint::from(3)));
+    assert_eq!(
+        hex::encode(encoded),
+        "600000000000000000000000000000000000000000000000000000000000000003"
+    );
+
+    // types of event params
+    let filter = events::placed::filter(types::Side::Sell);
+    let log = rethabi::RawLog {
+        topics: vec![
+            events::placed::event().signature(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000001").into(),
+        ],
+        data: hex!("0000000000000000000000000000000000000000000000000000000000000005").into(),
+    };
+    assert_eq!(filter.topic1, rethabi::Topic::This(log.topics[1]));
+    assert_eq!(
+        events::placed::parse_log(log).unwrap(),
+        logs::Placed { side: types::Side::Sell, price: types::Price(Uint::from(5)) }
+    );
+    let log = rethabi::RawLog {
+        topics: vec![events::status_changed::event().signature()],
+        data: hex!("0000000000000000000000000000000000000000000000000000000000000003").into(),
+    };
+    assert_eq!(
+        events::status_changed::parse_log(log).unwrap(),
+        logs::StatusChanged { status: types::Status(3) }
+    );
+}
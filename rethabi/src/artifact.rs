@@ -0,0 +1,425 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Build artifacts of contracts.
+
+use alloc::collections::BTreeMap;
+use std::io;
+
+use serde_json::{Map, Value};
+
+use crate::{errors, no_std_prelude::Cow, Bytecode, Contract, Error};
+
+/// Format of a build artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactFormat {
+    /// Bare ABI array, or an object holding nothing but the ABI under `abi`.
+    Abi,
+    /// Foundry `out/<file>/<name>.json`.
+    Foundry,
+    /// Hardhat `artifacts/<file>/<name>.json`.
+    Hardhat,
+    /// Truffle `build/contracts/<name>.json`.
+    Truffle,
+    /// Output of `solc --standard-json`.
+    StandardJson,
+}
+
+/// Contract loaded from a build artifact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Artifact {
+    /// Format the artifact was loaded from.
+    pub format: ArtifactFormat,
+    /// Contract name, if known.
+    pub name: Option<String>,
+    /// Source file the contract is declared in, if known.
+    pub source: Option<String>,
+    /// Contract ABI.
+    pub contract: Contract,
+    /// Creation bytecode, which may reference unlinked libraries.
+    pub bytecode: Option<Bytecode>,
+    /// Runtime bytecode, which may reference unlinked libraries.
+    pub deployed_bytecode: Option<Bytecode>,
+    /// Function selectors, maps function signature to selector.
+    pub method_identifiers: BTreeMap<String, [u8; 4]>,
+    /// Compiler metadata.
+    pub metadata: Option<Value>,
+}
+
+impl Artifact {
+    /// Loads a single contract from a bare ABI or a build artifact, detecting its format.
+    ///
+    /// Fails if the artifact holds more than one contract, use [`Artifact::load_all`] for those.
+    pub fn load<T: io::Read>(reader: T) -> errors::Result<Self> {
+        let mut artifacts = Self::load_all(reader)?;
+        match artifacts.len() {
+            1 => Ok(artifacts.remove(0)),
+            0 => Err(Error::Other(Cow::Borrowed("artifact does not contain any contract"))),
+            n => Err(Error::Other(Cow::Owned(format!("artifact contains {n} contracts")))),
+        }
+    }
+
+    /// Loads all contracts from a bare ABI or a build artifact, detecting its format.
+    pub fn load_all<T: io::Read>(reader: T) -> errors::Result<Vec<Self>> {
+        let value: Value = serde_json::from_reader(reader)?;
+        Self::from_value(value)
+    }
+
+    /// Reads all contracts from a bare ABI or a build artifact, detecting its format.
+    pub fn from_value(value: Value) -> errors::Result<Vec<Self>> {
+        let object = match value {
+            abi @ Value::Array(_) => {
+                return Ok(vec![Artifact::new(ArtifactFormat::Abi, serde_json::from_value(abi)?)])
+            }
+            Value::Object(object) => object,
+            _ => return Err(unknown_format()),
+        };
+
+        let format = detect_format(&object).ok_or_else(unknown_format)?;
+        match format {
+            ArtifactFormat::StandardJson => standard_json(object),
+            format => single(format, object).map(|artifact| vec![artifact]),
+        }
+    }
+
+    fn new(format: ArtifactFormat, contract: Contract) -> Self {
+        Artifact {
+            format,
+            name: None,
+            source: None,
+            contract,
+            bytecode: None,
+            deployed_bytecode: None,
+            method_identifiers: BTreeMap::new(),
+            metadata: None,
+        }
+    }
+}
+
+fn unknown_format() -> Error {
+    Error::Other(Cow::Borrowed("unknown artifact format"))
+}
+
+fn detect_format(object: &Map<String, Value>) -> Option<ArtifactFormat> {
+    if object.get("contracts").map_or(false, Value::is_object) {
+        return Some(ArtifactFormat::StandardJson);
+    }
+    if !object.contains_key("abi") {
+        return None;
+    }
+    let format = if object
+        .get("_format")
+        .and_then(Value::as_str)
+        .map_or(false, |format| format.starts_with("hh-sol-artifact"))
+    {
+        ArtifactFormat::Hardhat
+    } else if object.get("bytecode").map_or(false, Value::is_object) {
+        ArtifactFormat::Foundry
+    } else if object.contains_key("contractName") || object.contains_key("bytecode") {
+        ArtifactFormat::Truffle
+    } else {
+        ArtifactFormat::Abi
+    };
+    Some(format)
+}
+
+fn single(format: ArtifactFormat, mut object: Map<String, Value>) -> errors::Result<Artifact> {
+    let abi = object.remove("abi").unwrap_or(Value::Array(vec![]));
+    let mut artifact = Artifact::new(format, serde_json::from_value(abi)?);
+    artifact.name = string(object.get("contractName"));
+    artifact.source = string(object.get("sourceName"));
+
+    match format {
+        ArtifactFormat::Foundry => {
+            artifact.bytecode = bytecode(
+                object.get("bytecode").and_then(|b| b.get("object")),
+                object.get("bytecode").and_then(|b| b.get("linkReferences")),
+            )?;
+            artifact.deployed_bytecode = bytecode(
+                object.get("deployedBytecode").and_then(|b| b.get("object")),
+                object.get("deployedBytecode").and_then(|b| b.get("linkReferences")),
+            )?;
+            artifact.method_identifiers = method_identifiers(object.get("methodIdentifiers"))?;
+            artifact.metadata =
+                metadata(object.get("metadata").or_else(|| object.get("rawMetadata")));
+        }
+        _ => {
+            artifact.bytecode = bytecode(object.get("bytecode"), object.get("linkReferences"))?;
+            artifact.deployed_bytecode =
+                bytecode(object.get("deployedBytecode"), object.get("deployedLinkReferences"))?;
+            artifact.metadata = metadata(object.get("metadata"));
+        }
+    }
+
+    Ok(artifact)
+}
+
+fn standard_json(mut object: Map<String, Value>) -> errors::Result<Vec<Artifact>> {
+    let mut artifacts = vec![];
+    let files = match object.remove("contracts") {
+        Some(Value::Object(files)) => files,
+        _ => return Ok(artifacts),
+    };
+
+    for (source, contracts) in files {
+        let contracts = match contracts {
+            Value::Object(contracts) => contracts,
+            _ => continue,
+        };
+        for (name, mut contract) in contracts {
+            let abi = contract.get_mut("abi").map(Value::take).unwrap_or(Value::Array(vec![]));
+            let mut artifact =
+                Artifact::new(ArtifactFormat::StandardJson, serde_json::from_value(abi)?);
+            let evm = contract.get("evm");
+            let creation = evm.and_then(|evm| evm.get("bytecode"));
+            artifact.bytecode = bytecode(
+                creation.and_then(|bytecode| bytecode.get("object")),
+                creation.and_then(|bytecode| bytecode.get("linkReferences")),
+            )?;
+            let runtime = evm.and_then(|evm| evm.get("deployedBytecode"));
+            artifact.deployed_bytecode = bytecode(
+                runtime.and_then(|bytecode| bytecode.get("object")),
+                runtime.and_then(|bytecode| bytecode.get("linkReferences")),
+            )?;
+            artifact.method_identifiers =
+                method_identifiers(evm.and_then(|evm| evm.get("methodIdentifiers")))?;
+            artifact.metadata = metadata(contract.get("metadata"));
+            artifact.name = Some(name);
+            artifact.source = Some(source.clone());
+            artifacts.push(artifact);
+        }
+    }
+
+    Ok(artifacts)
+}
+
+fn string(value: Option<&Value>) -> Option<String> {
+    value.and_then(Value::as_str).map(ToOwned::to_owned)
+}
+
+/// Link references map source files to libraries to the placeholder locations, e.g.
+/// `{ "contracts/Math.sol": { "Math": [{ "start": 2, "length": 20 }] } }`.
+fn bytecode(
+    value: Option<&Value>,
+    link_references: Option<&Value>,
+) -> errors::Result<Option<Bytecode>> {
+    let bytecode = match value.and_then(Value::as_str) {
+        Some(bytecode) => bytecode.strip_prefix("0x").unwrap_or(bytecode),
+        None => return Ok(None),
+    };
+    if bytecode.is_empty() {
+        return Ok(None);
+    }
+
+    let mut bytecode = Bytecode::from_hex(bytecode)?;
+    let files = link_references.and_then(Value::as_object).into_iter().flatten();
+    for (file, libraries) in files {
+        for (library, locations) in libraries.as_object().into_iter().flatten() {
+            let name = format!("{file}:{library}");
+            let offsets = locations.as_array().into_iter().flatten();
+            for offset in offsets.filter_map(|location| location.get("start")?.as_u64()) {
+                bytecode.set_link_reference_name(offset as usize, &name);
+            }
+        }
+    }
+
+    Ok(Some(bytecode))
+}
+
+fn method_identifiers(value: Option<&Value>) -> errors::Result<BTreeMap<String, [u8; 4]>> {
+    let identifiers = match value.and_then(Value::as_object) {
+        Some(identifiers) => identifiers,
+        None => return Ok(BTreeMap::new()),
+    };
+
+    identifiers
+        .iter()
+        .map(|(signature, selector)| {
+            let selector = selector.as_str().ok_or(Error::InvalidData)?;
+            let mut result = [0u8; 4];
+            hex::decode_to_slice(selector.strip_prefix("0x").unwrap_or(selector), &mut result)?;
+            Ok((signature.clone(), result))
+        })
+        .collect()
+}
+
+/// Metadata is sometimes an object and sometimes a string holding json.
+fn metadata(value: Option<&Value>) -> Option<Value> {
+    match value? {
+        Value::Null => None,
+        Value::String(metadata) if metadata.is_empty() => None,
+        Value::String(metadata) => {
+            Some(serde_json::from_str(metadata).unwrap_or_else(|_| Value::String(metadata.clone())))
+        }
+        metadata => Some(metadata.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{Artifact, ArtifactFormat};
+    use crate::Bytecode;
+
+    const ABI: &str = r#"[
+		{
+			"type": "function",
+			"name": "foo",
+			"inputs": [{ "name": "a", "type": "uint256" }],
+			"outputs": []
+		}
+	]"#;
+
+    fn load(json: &str) -> Artifact {
+        Artifact::load(json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn bare_abi() {
+        let artifact = load(ABI);
+        assert_eq!(artifact.format, ArtifactFormat::Abi);
+        assert!(artifact.contract.function("foo").is_ok());
+        assert_eq!(artifact.bytecode, None);
+    }
+
+    #[test]
+    fn foundry() {
+        let json = format!(
+            r#"{{
+				"abi": {ABI},
+				"bytecode": {{ "object": "0x6080", "linkReferences": {{}} }},
+				"deployedBytecode": {{ "object": "0x6081", "linkReferences": {{}} }},
+				"methodIdentifiers": {{ "foo(uint256)": "2fbebd38" }},
+				"metadata": {{ "compiler": {{ "version": "0.8.19" }} }}
+			}}"#
+        );
+
+        let artifact = load(&json);
+        assert_eq!(artifact.format, ArtifactFormat::Foundry);
+        assert!(artifact.contract.function("foo").is_ok());
+        assert_eq!(artifact.bytecode.unwrap().to_bytes().unwrap(), hex!("6080"));
+        assert_eq!(artifact.deployed_bytecode.unwrap().to_bytes().unwrap(), hex!("6081"));
+        assert_eq!(artifact.method_identifiers["foo(uint256)"], hex!("2fbebd38"));
+        assert_eq!(artifact.metadata.unwrap()["compiler"]["version"], "0.8.19");
+    }
+
+    #[test]
+    fn hardhat() {
+        let json = format!(
+            r#"{{
+				"_format": "hh-sol-artifact-1",
+				"contractName": "Foo",
+				"sourceName": "contracts/Foo.sol",
+				"abi": {ABI},
+				"bytecode": "0x6080",
+				"deployedBytecode": "0x",
+				"linkReferences": {{}},
+				"deployedLinkReferences": {{}}
+			}}"#
+        );
+
+        let artifact = load(&json);
+        assert_eq!(artifact.format, ArtifactFormat::Hardhat);
+        assert_eq!(artifact.name.as_deref(), Some("Foo"));
+        assert_eq!(artifact.source.as_deref(), Some("contracts/Foo.sol"));
+        assert_eq!(artifact.bytecode.unwrap().to_bytes().unwrap(), hex!("6080"));
+        assert_eq!(artifact.deployed_bytecode, None);
+        assert!(artifact.method_identifiers.is_empty());
+    }
+
+    #[test]
+    fn hardhat_link_references() {
+        let placeholder = Bytecode::placeholder("contracts/Math.sol:Math");
+        let json = format!(
+            r#"{{
+				"_format": "hh-sol-artifact-1",
+				"contractName": "Foo",
+				"sourceName": "contracts/Foo.sol",
+				"abi": {ABI},
+				"bytecode": "0x6080{placeholder}",
+				"deployedBytecode": "0x",
+				"linkReferences": {{
+					"contracts/Math.sol": {{ "Math": [{{ "start": 2, "length": 20 }}] }}
+				}},
+				"deployedLinkReferences": {{}}
+			}}"#
+        );
+
+        let mut bytecode = load(&json).bytecode.unwrap();
+        assert_eq!(bytecode.link_references()[0].name.as_deref(), Some("contracts/Math.sol:Math"));
+        assert!(bytecode.to_bytes().is_err());
+        bytecode.link("contracts/Math.sol:Math", [0x11u8; 20].into()).unwrap();
+        assert_eq!(
+            bytecode.to_bytes().unwrap(),
+            hex!("60801111111111111111111111111111111111111111")
+        );
+    }
+
+    #[test]
+    fn truffle() {
+        let json = format!(
+            r#"{{
+				"contractName": "Foo",
+				"abi": {ABI},
+				"metadata": "{{\"language\":\"Solidity\"}}",
+				"bytecode": "0x6080",
+				"deployedBytecode": "0x6081",
+				"schemaVersion": "3.4.13"
+			}}"#
+        );
+
+        let artifact = load(&json);
+        assert_eq!(artifact.format, ArtifactFormat::Truffle);
+        assert_eq!(artifact.name.as_deref(), Some("Foo"));
+        assert_eq!(artifact.deployed_bytecode.unwrap().to_bytes().unwrap(), hex!("6081"));
+        assert_eq!(artifact.metadata.unwrap()["language"], "Solidity");
+    }
+
+    #[test]
+    fn standard_json() {
+        let json = format!(
+            r#"{{
+				"contracts": {{
+					"Foo.sol": {{
+						"Foo": {{
+							"abi": {ABI},
+							"evm": {{
+								"bytecode": {{ "object": "6080" }},
+								"deployedBytecode": {{ "object": "6081" }},
+								"methodIdentifiers": {{ "foo(uint256)": "2fbebd38" }}
+							}},
+							"metadata": "{{\"language\":\"Solidity\"}}"
+						}},
+						"Bar": {{
+							"abi": []
+						}}
+					}}
+				}},
+				"sources": {{}}
+			}}"#
+        );
+
+        let artifacts = Artifact::load_all(json.as_bytes()).unwrap();
+        assert_eq!(artifacts.len(), 2);
+        assert_eq!(artifacts[0].name.as_deref(), Some("Bar"));
+        assert_eq!(artifacts[1].name.as_deref(), Some("Foo"));
+        assert_eq!(artifacts[1].source.as_deref(), Some("Foo.sol"));
+        assert_eq!(artifacts[1].format, ArtifactFormat::StandardJson);
+        assert_eq!(artifacts[1].bytecode.as_ref().unwrap().to_bytes().unwrap(), hex!("6080"));
+        assert_eq!(artifacts[1].method_identifiers["foo(uint256)"], hex!("2fbebd38"));
+
+        assert!(Artifact::load(json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(Artifact::load(r#"{ "foo": [] }"#.as_bytes()).is_err());
+        assert!(Artifact::load("1".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,332 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Building contracts in code.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    contract::Item, error::Error as AbiError, no_std_prelude::Cow, param_type::Reader, Constructor,
+    Contract, Error, Event, EventParam, Fallback, Function, Param, ParamType, Receive, Result,
+    Severity, StateMutability,
+};
+
+/// Item being built.
+#[derive(Debug)]
+enum Current {
+    Constructor(Constructor),
+    Function(Function),
+    Event(Event),
+    Error(AbiError),
+    Receive(Receive),
+    Fallback(Fallback),
+}
+
+/// Fluent builder of a [`Contract`].
+///
+/// Params are added to the item started last, e.g.
+///
+/// ```
+/// use rethabi::{Contract, StateMutability};
+///
+/// let contract = Contract::builder()
+///     .function("transfer")
+///     .input("to", "address")
+///     .input("amount", "uint256")
+///     .output("", "bool")
+///     .event("Transfer")
+///     .indexed("from", "address")
+///     .indexed("to", "address")
+///     .input("value", "uint256")
+///     .function("balanceOf")
+///     .input("owner", "address")
+///     .output("", "uint256")
+///     .state_mutability(StateMutability::View)
+///     .build()
+///     .unwrap();
+/// let transfer = contract.function("transfer").unwrap();
+/// assert_eq!(transfer.signature(), "transfer(address,uint256):(bool)");
+/// ```
+///
+/// Misplaced calls, e.g. an output of an event, a second constructor, receive or fallback
+/// function, and types which fail to parse are reported by [`ContractBuilder::build`].
+#[derive(Debug, Default)]
+pub struct ContractBuilder {
+    contract: Contract,
+    current: Option<Current>,
+    error: Option<Error>,
+}
+
+impl Contract {
+    /// Creates a builder of a contract.
+    pub fn builder() -> ContractBuilder {
+        ContractBuilder::default()
+    }
+}
+
+impl ContractBuilder {
+    /// Starts the constructor.
+    pub fn constructor(mut self) -> Self {
+        self.finish();
+        self.current = Some(Current::Constructor(Constructor { inputs: vec![] }));
+        self
+    }
+
+    /// Starts a function, `nonpayable` unless set otherwise. Functions with the same name are
+    /// overloads.
+    pub fn function(mut self, name: &str) -> Self {
+        self.finish();
+        let function = Function::new(name, vec![], vec![], StateMutability::NonPayable);
+        self.current = Some(Current::Function(function));
+        self
+    }
+
+    /// Starts an event.
+    pub fn event(mut self, name: &str) -> Self {
+        self.finish();
+        let event = Event { name: name.to_owned(), inputs: vec![], anonymous: false };
+        self.current = Some(Current::Event(event));
+        self
+    }
+
+    /// Starts an error.
+    pub fn error(mut self, name: &str) -> Self {
+        self.finish();
+        self.current = Some(Current::Error(AbiError { name: name.to_owned(), inputs: vec![] }));
+        self
+    }
+
+    /// Starts the receive function.
+    pub fn receive(mut self) -> Self {
+        self.finish();
+        self.current = Some(Current::Receive(Receive::default()));
+        self
+    }
+
+    /// Starts the fallback function, `nonpayable` unless set otherwise.
+    pub fn fallback(mut self) -> Self {
+        self.finish();
+        self.current = Some(Current::Fallback(Fallback::default()));
+        self
+    }
+
+    /// Adds an input param of type `ty`, e.g. `uint256` or `(address,bytes)[]`, to the
+    /// current constructor, function or error, or a non-indexed param to the current event.
+    pub fn input(mut self, name: &str, ty: &str) -> Self {
+        let Some(kind) = self.read(ty) else { return self };
+        let param = Param::new(name, kind.clone());
+        match &mut self.current {
+            Some(Current::Constructor(constructor)) => constructor.inputs.push(param),
+            Some(Current::Function(function)) => function.inputs.push(param),
+            Some(Current::Error(error)) => error.inputs.push(param),
+            Some(Current::Event(event)) => event.inputs.push(EventParam::new(name, kind, false)),
+            _ => self.misplaced("input", "a constructor, function, event or error"),
+        }
+        self
+    }
+
+    /// Adds an indexed param of type `ty` to the current event.
+    pub fn indexed(mut self, name: &str, ty: &str) -> Self {
+        let Some(kind) = self.read(ty) else { return self };
+        match &mut self.current {
+            Some(Current::Event(event)) => event.inputs.push(EventParam::new(name, kind, true)),
+            _ => self.misplaced("indexed", "an event"),
+        }
+        self
+    }
+
+    /// Adds an output param of type `ty` to the current function.
+    pub fn output(mut self, name: &str, ty: &str) -> Self {
+        let Some(kind) = self.read(ty) else { return self };
+        match &mut self.current {
+            Some(Current::Function(function)) => function.outputs.push(Param::new(name, kind)),
+            _ => self.misplaced("output", "a function"),
+        }
+        self
+    }
+
+    /// Sets the state mutability of the current function, receive or fallback function.
+    pub fn state_mutability(mut self, state_mutability: StateMutability) -> Self {
+        match &mut self.current {
+            Some(Current::Function(function)) => function.state_mutability = state_mutability,
+            Some(Current::Receive(receive)) => receive.state_mutability = state_mutability,
+            Some(Current::Fallback(fallback)) => fallback.state_mutability = state_mutability,
+            _ => self.misplaced("state_mutability", "a function"),
+        }
+        self
+    }
+
+    /// Makes the current event anonymous.
+    pub fn anonymous(mut self) -> Self {
+        match &mut self.current {
+            Some(Current::Event(event)) => event.anonymous = true,
+            _ => self.misplaced("anonymous", "an event"),
+        }
+        self
+    }
+
+    /// Builds the contract.
+    ///
+    /// Fails with the first misplaced call or invalid type, or if [`Contract::validate`] finds
+    /// an error, such as two overloads with the same params.
+    pub fn build(mut self) -> Result<Contract> {
+        self.finish();
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let diagnostics = self.contract.validate();
+        match diagnostics.iter().find(|diagnostic| diagnostic.severity == Severity::Error) {
+            Some(diagnostic) => Err(Error::Other(Cow::Owned(diagnostic.to_string()))),
+            None => Ok(self.contract),
+        }
+    }
+
+    /// Adds the current item to the contract, keeping the first constructor, receive and
+    /// fallback function if started twice.
+    fn finish(&mut self) {
+        match self.current.take() {
+            Some(Current::Constructor(_)) if self.contract.constructor.is_some() => {
+                self.duplicate("constructor")
+            }
+            Some(Current::Constructor(constructor)) => {
+                self.contract.constructor = Some(constructor);
+                self.contract.items.push(Item::Constructor);
+            }
+            Some(Current::Function(function)) => self.contract.add_function(function),
+            Some(Current::Event(event)) => self.contract.add_event(event),
+            Some(Current::Error(error)) => self.contract.add_error(error),
+            Some(Current::Receive(_)) if self.contract.receive.is_some() => {
+                self.duplicate("receive")
+            }
+            Some(Current::Receive(receive)) => {
+                self.contract.receive = Some(receive);
+                self.contract.items.push(Item::Receive);
+            }
+            Some(Current::Fallback(_)) if self.contract.fallback.is_some() => {
+                self.duplicate("fallback")
+            }
+            Some(Current::Fallback(fallback)) => {
+                self.contract.fallback = Some(fallback);
+                self.contract.items.push(Item::Fallback);
+            }
+            None => {}
+        }
+    }
+
+    fn read(&mut self, ty: &str) -> Option<ParamType> {
+        match Reader::read(ty) {
+            Ok(kind) => Some(kind),
+            Err(error) => {
+                self.error.get_or_insert(error);
+                None
+            }
+        }
+    }
+
+    fn misplaced(&mut self, method: &str, items: &str) {
+        let error = Error::Other(Cow::Owned(format!("`{method}` is only valid for {items}")));
+        self.error.get_or_insert(error);
+    }
+
+    fn duplicate(&mut self, item: &str) {
+        let error = Error::Other(Cow::Owned(format!("`{item}` is already declared")));
+        self.error.get_or_insert(error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{Contract, Error, Item, ParamType, StateMutability};
+
+    #[test]
+    fn build() {
+        let contract = Contract::builder()
+            .constructor()
+            .input("owner", "address")
+            .function("get")
+            .output("", "uint256")
+            .state_mutability(StateMutability::View)
+            .function("get")
+            .input("key", "bytes32")
+            .output("", "uint256")
+            .state_mutability(StateMutability::View)
+            .event("Set")
+            .indexed("key", "bytes32")
+            .input("entry", "(uint8,bool)[]")
+            .anonymous()
+            .error("Missing")
+            .input("key", "bytes32")
+            .receive()
+            .fallback()
+            .state_mutability(StateMutability::Payable)
+            .build()
+            .unwrap();
+
+        let constructor = contract.constructor().unwrap();
+        assert_eq!(constructor.inputs[0].kind, ParamType::Address);
+        let overloads = contract.functions_by_name("get").unwrap();
+        assert_eq!(overloads.len(), 2);
+        assert_eq!(overloads[1].signature(), "get(bytes32):(uint256)");
+        #[allow(deprecated)]
+        let constant = overloads[1].constant;
+        assert_eq!(constant, None);
+
+        let event = contract.event("Set").unwrap();
+        assert!(event.anonymous);
+        assert!(event.inputs[0].indexed);
+        assert_eq!(
+            event.inputs[1].kind,
+            ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Uint(8), ParamType::Bool])))
+        );
+        assert_eq!(contract.error("Missing").unwrap().inputs.len(), 1);
+        assert_eq!(contract.receive.unwrap().state_mutability, StateMutability::Payable);
+        assert_eq!(contract.fallback.unwrap().state_mutability, StateMutability::Payable);
+
+        assert_eq!(contract.items[0], Item::Constructor);
+        assert_eq!(contract.items[2], Item::Function("get".into(), 1));
+        assert_eq!(contract.items[5..], [Item::Receive, Item::Fallback]);
+    }
+
+    #[test]
+    fn build_errors() {
+        assert!(matches!(
+            Contract::builder().function("f").input("a", "address)").build(),
+            Err(Error::InvalidName(_))
+        ));
+        assert!(matches!(
+            Contract::builder().event("E").output("", "bool").build(),
+            Err(Error::Other(_))
+        ));
+        assert!(matches!(
+            Contract::builder().function("f").anonymous().build(),
+            Err(Error::Other(_))
+        ));
+        assert!(matches!(Contract::builder().input("a", "bool").build(), Err(Error::Other(_))));
+        assert!(matches!(
+            Contract::builder()
+                .function("f")
+                .input("a", "bool")
+                .function("f")
+                .input("b", "bool")
+                .build(),
+            Err(Error::Other(_))
+        ));
+        for builder in [
+            Contract::builder().constructor().input("a", "bool").constructor(),
+            Contract::builder().receive().function("f").receive(),
+            Contract::builder().fallback().fallback(),
+        ] {
+            match builder.build() {
+                Err(Error::Other(message)) => assert!(message.ends_with("is already declared")),
+                result => panic!("unexpected result: {result:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Contract bytecode with library link references.
+
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{no_std_prelude::Cow, Address, Bytes, Error, Result};
+
+/// Length of a placeholder in hex encoded bytecode, which is the length of an address.
+const PLACEHOLDER_LEN: usize = 40;
+
+/// Length of the hash in `__$<hash>$__` placeholders.
+const PLACEHOLDER_HASH_LEN: usize = 34;
+
+/// Contract bytecode, which may reference libraries that are not linked yet.
+///
+/// Unlinked libraries show up as placeholders in hex encoded bytecode, either as
+/// `__$<hash>$__`, where `<hash>` is taken from the keccak hash of the fully qualified library
+/// name, or as `__<name>__` padded with underscores for compilers before solidity 0.5.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bytecode {
+    /// Bytecode, with placeholders zeroed out.
+    code: Bytes,
+    /// Libraries that still have to be linked.
+    link_references: Vec<LinkReference>,
+}
+
+/// Library referenced by bytecode which is not linked yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkReference {
+    /// Placeholder as found in the hex encoded bytecode, e.g. `__$<hash>$__`.
+    pub placeholder: String,
+    /// Fully qualified library name, e.g. `contracts/Math.sol:Math`, if known.
+    pub name: Option<String>,
+    /// Offsets of the placeholder occurrences in the bytecode, in bytes.
+    pub offsets: Vec<usize>,
+}
+
+impl LinkReference {
+    fn matches(&self, name: &str) -> bool {
+        if self.name.as_deref() == Some(name) || self.placeholder == Bytecode::placeholder(name) {
+            return true;
+        }
+
+        // Legacy placeholders hold the name truncated to fit, or only the library name.
+        let library = name.rsplit(':').next().unwrap_or(name);
+        [name, library].iter().any(|name| self.placeholder == Bytecode::legacy_placeholder(name))
+    }
+}
+
+impl From<Bytes> for Bytecode {
+    fn from(code: Bytes) -> Self {
+        Bytecode { code, link_references: vec![] }
+    }
+}
+
+impl Bytecode {
+    /// Parses hex encoded bytecode, with or without `0x` prefix, which may contain placeholders
+    /// of unlinked libraries.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+        let mut code = Vec::with_capacity(hex.len() / 2);
+        let mut link_references: Vec<LinkReference> = vec![];
+
+        let mut rest = hex;
+        while !rest.is_empty() {
+            match rest.find("__") {
+                // Placeholders are aligned to bytes.
+                Some(start) if start % 2 == 0 => {
+                    let (before, placeholder) = rest.split_at(start);
+                    code.extend(hex::decode(before).map_err(|_| Error::InvalidData)?);
+
+                    let placeholder =
+                        placeholder.get(..PLACEHOLDER_LEN).ok_or(Error::InvalidData)?;
+                    if !placeholder.ends_with("__") {
+                        return Err(Error::InvalidData);
+                    }
+                    let offset = code.len();
+                    match link_references.iter_mut().find(|r| r.placeholder == placeholder) {
+                        Some(reference) => reference.offsets.push(offset),
+                        None => link_references.push(LinkReference {
+                            placeholder: placeholder.to_owned(),
+                            name: None,
+                            offsets: vec![offset],
+                        }),
+                    }
+                    code.extend([0u8; PLACEHOLDER_LEN / 2]);
+
+                    rest = &rest[start + PLACEHOLDER_LEN..];
+                }
+                Some(_) => return Err(Error::InvalidData),
+                None => {
+                    code.extend(hex::decode(rest).map_err(|_| Error::InvalidData)?);
+                    rest = "";
+                }
+            }
+        }
+
+        Ok(Bytecode { code, link_references })
+    }
+
+    /// Returns the placeholder used by solidity for the library with the given fully
+    /// qualified name, e.g. `contracts/Math.sol:Math`.
+    pub fn placeholder(name: &str) -> String {
+        let hash = hex::encode(Keccak256::digest(name.as_bytes()));
+        format!("__${}$__", &hash[..PLACEHOLDER_HASH_LEN])
+    }
+
+    /// Returns the placeholder used by compilers before solidity 0.5, `__<name>__` with the name
+    /// truncated to fit and padded with underscores.
+    fn legacy_placeholder(name: &str) -> String {
+        let len = PLACEHOLDER_LEN - 4;
+        let name = name.char_indices().nth(len).map_or(name, |(i, _)| &name[..i]);
+        format!("__{name:_<len$}__")
+    }
+
+    /// Names the library referenced at the given offset, as given by the `linkReferences`
+    /// of build artifacts.
+    pub fn set_link_reference_name(&mut self, offset: usize, name: &str) {
+        if let Some(reference) =
+            self.link_references.iter_mut().find(|reference| reference.offsets.contains(&offset))
+        {
+            reference.name = Some(name.to_owned());
+        }
+    }
+
+    /// Libraries that still have to be linked.
+    pub fn link_references(&self) -> &[LinkReference] {
+        &self.link_references
+    }
+
+    /// Returns whether all libraries are linked.
+    pub fn is_linked(&self) -> bool {
+        self.link_references.is_empty()
+    }
+
+    /// Links the library with the given fully qualified name, e.g. `contracts/Math.sol:Math`,
+    /// replacing its placeholders with `address`.
+    pub fn link(&mut self, name: &str, address: Address) -> Result<()> {
+        let index = self
+            .link_references
+            .iter()
+            .position(|reference| reference.matches(name))
+            .ok_or_else(|| Error::InvalidName(name.to_owned()))?;
+
+        let reference = self.link_references.remove(index);
+        for offset in reference.offsets {
+            self.code[offset..offset + PLACEHOLDER_LEN / 2].copy_from_slice(address.as_ref());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the bytecode, failing if any library is not linked yet.
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        match self.link_references.first() {
+            None => Ok(self.code.clone()),
+            Some(reference) => Err(Error::Other(Cow::Owned(format!(
+                "unlinked library: {}",
+                reference.name.as_deref().unwrap_or(&reference.placeholder)
+            )))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{Address, Bytecode, Constructor, Error, Param, ParamType, Token};
+
+    const MATH: &str = "contracts/Math.sol:Math";
+
+    fn unlinked() -> String {
+        let placeholder = Bytecode::placeholder(MATH);
+        format!("0x6080{placeholder}6081{placeholder}")
+    }
+
+    #[test]
+    fn placeholder() {
+        assert_eq!(Bytecode::placeholder(MATH).len(), 40);
+        assert!(Bytecode::placeholder(MATH).starts_with("__$"));
+        assert!(Bytecode::placeholder(MATH).ends_with("$__"));
+    }
+
+    #[test]
+    fn parse_without_placeholders() {
+        let bytecode = Bytecode::from_hex("0x60806040").unwrap();
+        assert!(bytecode.is_linked());
+        assert_eq!(bytecode.to_bytes().unwrap(), hex!("60806040"));
+    }
+
+    #[test]
+    fn link_by_name() {
+        let mut bytecode = Bytecode::from_hex(&unlinked()).unwrap();
+        assert_eq!(bytecode.link_references().len(), 1);
+        assert_eq!(bytecode.link_references()[0].offsets, vec![2, 24]);
+        assert!(bytecode.to_bytes().is_err());
+
+        assert!(bytecode.link("contracts/Other.sol:Other", Address::from([0x11u8; 20])).is_err());
+        bytecode.link(MATH, Address::from([0x11u8; 20])).unwrap();
+        assert!(bytecode.is_linked());
+        assert_eq!(
+            bytecode.to_bytes().unwrap(),
+            hex!(
+                "
+				6080 1111111111111111111111111111111111111111
+				6081 1111111111111111111111111111111111111111
+			"
+            )
+        );
+    }
+
+    #[test]
+    fn link_legacy_placeholder() {
+        let mut bytecode = Bytecode::from_hex(&format!("6080{:_<40}", "__Math.sol:Math")).unwrap();
+        assert_eq!(bytecode.link_references()[0].name, None);
+        bytecode.link("Math.sol:Math", Address::from([0x22u8; 20])).unwrap();
+        assert_eq!(
+            bytecode.to_bytes().unwrap(),
+            hex!("60802222222222222222222222222222222222222222")
+        );
+    }
+
+    #[test]
+    fn link_legacy_placeholder_with_underscores() {
+        let mut bytecode = Bytecode::from_hex(&format!("6080{:_<40}", "___Math_")).unwrap();
+        assert!(bytecode.link("Math", Address::from([0x22u8; 20])).is_err());
+        bytecode.link("_Math_", Address::from([0x22u8; 20])).unwrap();
+        assert!(bytecode.is_linked());
+
+        let name = format!("{}:Math", "ä".repeat(20));
+        let mut bytecode = Bytecode::from_hex(&unlinked()).unwrap();
+        assert!(bytecode.link(&name, Address::from([0x22u8; 20])).is_err());
+    }
+
+    #[test]
+    fn named_link_reference() {
+        let mut bytecode = Bytecode::from_hex(&unlinked()).unwrap();
+        bytecode.set_link_reference_name(24, MATH);
+        assert_eq!(bytecode.link_references()[0].name.as_deref(), Some(MATH));
+        match bytecode.to_bytes() {
+            Err(Error::Other(message)) => assert_eq!(message, format!("unlinked library: {MATH}")),
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_placeholder() {
+        assert!(Bytecode::from_hex("6080__$1234$__").is_err());
+        assert!(Bytecode::from_hex(&format!("608{:_<40}", "__Math.sol:Math")).is_err());
+    }
+
+    #[test]
+    fn constructor_encode_input_with_bytecode() {
+        let constructor = Constructor { inputs: vec![Param::new("a", ParamType::Bool)] };
+        let mut bytecode = Bytecode::from_hex(&unlinked()).unwrap();
+        let tokens = [Token::Bool(true)];
+
+        assert!(constructor.encode_input_with_bytecode(&bytecode, &tokens).is_err());
+
+        bytecode.link(MATH, Address::from([0x11u8; 20])).unwrap();
+        assert_eq!(
+            constructor.encode_input_with_bytecode(&bytecode, &tokens).unwrap(),
+            hex!(
+                "
+				6080 1111111111111111111111111111111111111111
+				6081 1111111111111111111111111111111111111111
+				0000000000000000000000000000000000000000000000000000000000000001
+			"
+            )
+        );
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Canonical form of contracts.
+
+#[cfg(feature = "full-serde")]
+use crate::errors;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    param::named_components, signature::signature_string, Contract, Param, ParamType,
+    StateMutability, TupleParam,
+};
+
+/// What [`Contract::canonicalize`] removes in addition to the fields without meaning.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CanonicalOptions {
+    /// Removes the names of params and tuple components.
+    pub strip_names: bool,
+    /// Removes the `internalType` of params and tuple components.
+    pub strip_internal_types: bool,
+}
+
+impl Contract {
+    /// Returns the contract in canonical form.
+    ///
+    /// Items are listed in the order of the maps and overloads are sorted by signature, the
+    /// deprecated `constant` flag is folded into the state mutability and unnamed tuple
+    /// components, or components which do not match the param type, are removed, as they are
+    /// implied by it.
+    pub fn canonicalize(&self, options: CanonicalOptions) -> Contract {
+        let mut contract = self.clone();
+
+        if let Some(constructor) = &mut contract.constructor {
+            canonicalize_params(&mut constructor.inputs, options);
+        }
+
+        for functions in contract.functions.values_mut() {
+            for function in functions.iter_mut() {
+                #[allow(deprecated)]
+                if function.constant.take() == Some(true)
+                    && function.state_mutability == StateMutability::NonPayable
+                {
+                    function.state_mutability = StateMutability::View;
+                }
+                canonicalize_params(&mut function.inputs, options);
+                canonicalize_params(&mut function.outputs, options);
+            }
+            functions.sort_by_cached_key(|function| signature_string("", &function.inputs));
+        }
+
+        for events in contract.events.values_mut() {
+            for event in events.iter_mut() {
+                for param in &mut event.inputs {
+                    if options.strip_names {
+                        param.name.clear();
+                    }
+                    if options.strip_internal_types {
+                        param.internal_type = None;
+                    }
+                    canonicalize_components(&param.kind, &mut param.components, options);
+                }
+            }
+            events.sort_by_cached_key(|event| {
+                (signature_string(&event.name, &event.inputs), event.anonymous)
+            });
+        }
+
+        for errors in contract.errors.values_mut() {
+            for error in errors.iter_mut() {
+                canonicalize_params(&mut error.inputs, options);
+            }
+            errors.sort_by_cached_key(|error| signature_string("", &error.inputs));
+        }
+
+        contract.items = contract.default_items();
+        contract
+    }
+
+    /// Serializes the contract in canonical form to compact JSON.
+    ///
+    /// Contracts which only differ in the order of their items, or in what the options strip,
+    /// serialize to the same bytes, so the result can be hashed to identify an ABI.
+    #[cfg(feature = "full-serde")]
+    pub fn to_canonical_json(&self, options: CanonicalOptions) -> errors::Result<String> {
+        Ok(serde_json::to_string(&self.canonicalize(options))?)
+    }
+}
+
+fn canonicalize_params(params: &mut [Param], options: CanonicalOptions) {
+    for param in params {
+        if options.strip_names {
+            param.name.clear();
+        }
+        if options.strip_internal_types {
+            param.internal_type = None;
+        }
+        canonicalize_components(&param.kind, &mut param.components, options);
+    }
+}
+
+fn canonicalize_components(
+    kind: &ParamType,
+    components: &mut Vec<TupleParam>,
+    options: CanonicalOptions,
+) {
+    if named_components(kind, components).is_empty() {
+        components.clear();
+    }
+    for component in components.iter_mut() {
+        if options.strip_names || component.name.as_deref() == Some("") {
+            component.name = None;
+        }
+        if options.strip_internal_types {
+            component.internal_type = None;
+        }
+        canonicalize_components(&component.kind, &mut component.components, options);
+    }
+
+    if components
+        .iter()
+        .all(|c| c.name.is_none() && c.internal_type.is_none() && c.components.is_empty())
+    {
+        components.clear();
+    }
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use crate::{CanonicalOptions, Contract};
+
+    const ABI: &str = r#"[
+		{
+			"type": "function",
+			"name": "get",
+			"inputs": [{ "name": "key", "type": "bytes32", "internalType": "bytes32" }],
+			"outputs": [{ "name": "", "type": "uint256" }],
+			"constant": true
+		},
+		{
+			"type": "function",
+			"name": "get",
+			"inputs": [],
+			"outputs": [
+				{
+					"name": "entry",
+					"type": "tuple",
+					"internalType": "struct Store.Entry",
+					"components": [
+						{ "name": "", "type": "uint8" },
+						{ "name": "", "type": "bool" }
+					]
+				}
+			],
+			"stateMutability": "view"
+		},
+		{
+			"type": "event",
+			"name": "Set",
+			"inputs": [
+				{ "name": "key", "type": "bytes32", "indexed": true, "internalType": "Store.Key" }
+			],
+			"anonymous": false
+		}
+	]"#;
+
+    const REORDERED: &str = r#"[
+		{
+			"anonymous": false,
+			"inputs": [{ "indexed": true, "name": "key", "type": "bytes32" }],
+			"name": "Set",
+			"type": "event"
+		},
+		{
+			"inputs": [],
+			"name": "get",
+			"outputs": [
+				{ "components": [{ "type": "uint8" }, { "type": "bool" }], "name": "entry", "type": "tuple" }
+			],
+			"stateMutability": "view",
+			"type": "function"
+		},
+		{
+			"inputs": [{ "name": "id", "type": "bytes32" }],
+			"name": "get",
+			"outputs": [{ "name": "", "type": "uint256" }],
+			"stateMutability": "view",
+			"type": "function"
+		}
+	]"#;
+
+    #[test]
+    fn canonical_json() {
+        let contract: Contract = serde_json::from_str(ABI).unwrap();
+        let reordered: Contract = serde_json::from_str(REORDERED).unwrap();
+
+        let canonical = contract.to_canonical_json(CanonicalOptions::default()).unwrap();
+        assert_eq!(
+            canonical,
+            concat!(
+                r#"[{"type":"function","name":"get","inputs":[],"outputs":[{"internalType":"struct Store.Entry","name":"entry","type":"tuple","components":[{"type":"uint8"},{"type":"bool"}]}],"stateMutability":"view"},"#,
+                r#"{"type":"function","name":"get","inputs":[{"internalType":"bytes32","name":"key","type":"bytes32"}],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"},"#,
+                r#"{"type":"event","name":"Set","inputs":[{"name":"key","type":"bytes32","indexed":true,"internalType":"Store.Key"}],"anonymous":false}]"#,
+            )
+        );
+
+        let options = CanonicalOptions { strip_names: false, strip_internal_types: true };
+        assert_ne!(
+            contract.to_canonical_json(options).unwrap(),
+            reordered.to_canonical_json(options).unwrap()
+        );
+
+        let options = CanonicalOptions { strip_names: true, strip_internal_types: true };
+        assert_eq!(
+            contract.to_canonical_json(options).unwrap(),
+            reordered.to_canonical_json(options).unwrap()
+        );
+        assert_eq!(contract.canonicalize(options), reordered.canonicalize(options));
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Selector collisions between functions of contracts sharing a dispatcher.
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{signature::signature_string, Contract, Function};
+
+/// Functions with different signatures, or declared by different contracts, which share a
+/// selector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectorCollision<'a> {
+    /// The shared selector.
+    pub selector: [u8; 4],
+    /// The colliding functions, with the index of the contract declaring them.
+    pub functions: Vec<(usize, &'a Function)>,
+}
+
+impl SelectorCollision<'_> {
+    /// Returns the signatures of the colliding functions, e.g. `transfer(address,uint256)`.
+    pub fn signatures(&self) -> impl Iterator<Item = String> + '_ {
+        self.functions.iter().map(|(_, function)| function_signature(function))
+    }
+}
+
+impl fmt::Display for SelectorCollision<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x{}:", hex::encode(self.selector))?;
+        for (i, (index, function)) in self.functions.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(f, "{} {} in contract {}", separator, function_signature(function), index)?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the selectors shared by functions of the contracts, e.g. the facets of an EIP-2535
+/// diamond or a proxy and its implementation, ordered by selector.
+///
+/// Overloads of one contract are compared as well. A function declared with the same signature
+/// by several contracts collides too, as only one of them can be reached through the dispatcher.
+pub fn selector_collisions<'a, I>(contracts: I) -> Vec<SelectorCollision<'a>>
+where
+    I: IntoIterator<Item = &'a Contract>,
+{
+    let mut selectors: BTreeMap<[u8; 4], Vec<(usize, &'a Function)>> = BTreeMap::new();
+    for (index, contract) in contracts.into_iter().enumerate() {
+        for function in contract.functions() {
+            selectors.entry(function.short_signature()).or_default().push((index, function));
+        }
+    }
+
+    selectors
+        .into_iter()
+        .filter_map(|(selector, mut functions)| {
+            // Duplicates within a contract are reported by `Contract::validate`.
+            functions.dedup_by(|(a, fa), (b, fb)| {
+                a == b && function_signature(fa) == function_signature(fb)
+            });
+            (functions.len() > 1).then_some(SelectorCollision { selector, functions })
+        })
+        .collect()
+}
+
+impl Contract {
+    /// Finds the selectors shared by overloads, or other functions, of the contract.
+    pub fn selector_collisions(&self) -> Vec<SelectorCollision<'_>> {
+        selector_collisions([self])
+    }
+}
+
+fn function_signature(function: &Function) -> String {
+    signature_string(&function.name, &function.inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::selector_collisions;
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{Contract, Function, Param, ParamType, StateMutability};
+
+    fn function(name: &str, kinds: Vec<ParamType>) -> Function {
+        #[allow(deprecated)]
+        Function {
+            name: name.into(),
+            inputs: kinds.into_iter().map(|kind| Param::new("", kind)).collect(),
+            outputs: vec![],
+            constant: None,
+            state_mutability: StateMutability::NonPayable,
+        }
+    }
+
+    fn contract(functions: Vec<Function>) -> Contract {
+        let mut contract = Contract::default();
+        for function in functions {
+            contract.functions.entry(function.name.clone()).or_default().push(function);
+        }
+        contract
+    }
+
+    #[test]
+    fn collisions_across_contracts() {
+        let token = contract(vec![
+            function("transfer", vec![ParamType::Address, ParamType::Uint(256)]),
+            function("approve", vec![ParamType::Address]),
+        ]);
+        let facet = contract(vec![
+            function("many_msg_babbage", vec![ParamType::FixedBytes(1)]),
+            function("approve", vec![ParamType::Address]),
+        ]);
+
+        let collisions = selector_collisions([&token, &facet]);
+        assert_eq!(collisions.len(), 2);
+
+        assert_eq!(collisions[0].selector, [0xa9, 0x05, 0x9c, 0xbb]);
+        assert_eq!(
+            collisions[0].to_string(),
+            "0xa9059cbb: transfer(address,uint256) in contract 0, many_msg_babbage(bytes1) in contract 1"
+        );
+        assert_eq!(
+            collisions[1].signatures().collect::<Vec<_>>(),
+            ["approve(address)", "approve(address)"]
+        );
+        assert_eq!(collisions[1].functions.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [0, 1]);
+
+        assert!(token.selector_collisions().is_empty());
+    }
+
+    #[test]
+    fn collisions_within_contract() {
+        let contract = contract(vec![
+            function("burn", vec![ParamType::Uint(256)]),
+            function("collate_propagate_storage", vec![ParamType::FixedBytes(16)]),
+            function("burn", vec![ParamType::Address]),
+        ]);
+
+        let collisions = contract.selector_collisions();
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(
+            collisions[0].to_string(),
+            "0x42966c68: burn(uint256) in contract 0, collate_propagate_storage(bytes16) in contract 0"
+        );
+    }
+}
@@ -13,7 +13,11 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{encode, Bytes, Error, Param, ParamType, Result, Token};
+use crate::{
+    create::{create2_address, create_address, init_code_hash},
+    decode, encode, Address, Bytecode, Bytes, Deployment, Error, Hash, Param, ParamType, Result,
+    Token,
+};
 
 /// Contract constructor specification.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -39,4 +43,158 @@ impl Constructor {
             Err(Error::InvalidData)
         }
     }
+
+    /// Prepares ABI constructor call with given input params, failing if the bytecode still
+    /// references unlinked libraries.
+    pub fn encode_input_with_bytecode(&self, code: &Bytecode, tokens: &[Token]) -> Result<Bytes> {
+        self.encode_input(code.to_bytes()?, tokens)
+    }
+
+    /// Prepares a deployment with `CREATE` by `sender` at the given nonce.
+    pub fn create(
+        &self,
+        code: Bytes,
+        tokens: &[Token],
+        sender: Address,
+        nonce: u64,
+    ) -> Result<Deployment> {
+        let init_code = self.encode_input(code, tokens)?;
+        Ok(Deployment { init_code, address: create_address(sender, nonce) })
+    }
+
+    /// Prepares a deployment with `CREATE2` by `sender` with the given salt.
+    pub fn create2(
+        &self,
+        code: Bytes,
+        tokens: &[Token],
+        sender: Address,
+        salt: Hash,
+    ) -> Result<Deployment> {
+        let init_code = self.encode_input(code, tokens)?;
+        let address = create2_address(sender, salt, init_code_hash(&init_code));
+        Ok(Deployment { init_code, address })
+    }
+
+    /// Parses the constructor arguments of the init code of a deployment of `code`.
+    pub fn decode_input(&self, init_code: &[u8], code: &[u8]) -> Result<Vec<Token>> {
+        let args = init_code.strip_prefix(code).ok_or(Error::InvalidData)?;
+        decode_exact(&self.param_types(), args).ok_or(Error::InvalidData)
+    }
+
+    /// Parses the constructor arguments of init code, locating where the arguments start.
+    ///
+    /// If `code` is known, it only has to match the init code up to the metadata appended by
+    /// solc, so bytecode compiled from the same sources at another path is accepted too.
+    /// Otherwise the arguments are taken to be the shortest tail of the init code which is a
+    /// valid encoding of the inputs, preferring tails that follow the metadata.
+    pub fn decode_input_heuristic(
+        &self,
+        init_code: &[u8],
+        code: Option<&[u8]>,
+    ) -> Result<Vec<Token>> {
+        let params = self.param_types();
+        let decode_args = |start: usize| decode_exact(&params, &init_code[start..]);
+
+        if let Some(code) = code {
+            if code.len() > init_code.len() {
+                return Err(Error::InvalidData);
+            }
+            let len = code.len() - metadata_len(code).unwrap_or(0);
+            if code[..len] != init_code[..len] {
+                return Err(Error::InvalidData);
+            }
+            return decode_args(code.len()).ok_or(Error::InvalidData);
+        }
+
+        let mut fallback = None;
+        for start in (0..=init_code.len()).rev().step_by(32) {
+            if let Some(tokens) = decode_args(start) {
+                if metadata_len(&init_code[..start]).is_some() {
+                    return Ok(tokens);
+                }
+                fallback = fallback.or(Some(tokens));
+            }
+        }
+        fallback.ok_or(Error::InvalidData)
+    }
+}
+
+/// Decodes `data`, which must be exactly the encoding of the tokens.
+fn decode_exact(types: &[ParamType], data: &[u8]) -> Option<Vec<Token>> {
+    decode(types, data).ok().filter(|tokens| encode(tokens) == data)
+}
+
+/// Returns the length of the CBOR encoded metadata solc appends to bytecode, including the two
+/// bytes holding its length, if the bytecode ends with metadata.
+fn metadata_len(code: &[u8]) -> Option<usize> {
+    let (rest, len) = code.split_at(code.len().checked_sub(2)?);
+    let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+    // Metadata is a CBOR map with a few entries, e.g. `ipfs` and `solc`.
+    match rest.len().checked_sub(len).and_then(|start| rest.get(start)) {
+        Some(0xa1..=0xa5) => Some(len + 2),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{Constructor, Param, ParamType, Token, Uint};
+
+    fn constructor() -> Constructor {
+        Constructor {
+            inputs: vec![Param::new("a", ParamType::Uint(256)), Param::new("b", ParamType::String)],
+        }
+    }
+
+    /// Bytecode ending with metadata `{ "ipfs": <hash> }`.
+    fn code(hash: u8) -> Vec<u8> {
+        let mut code = hex!("6080604052a1646970667358").to_vec();
+        code.push(hash);
+        code.extend(hex!("0008"));
+        code
+    }
+
+    fn tokens() -> Vec<Token> {
+        vec![Token::Uint(Uint::from(7)), Token::String("hello".into())]
+    }
+
+    #[test]
+    fn decode_input() {
+        let constructor = constructor();
+        let init_code = constructor.encode_input(code(1), &tokens()).unwrap();
+
+        assert_eq!(constructor.decode_input(&init_code, &code(1)).unwrap(), tokens());
+        assert!(constructor.decode_input(&init_code, &code(2)).is_err());
+        assert!(constructor.decode_input(&init_code[..init_code.len() - 1], &code(1)).is_err());
+    }
+
+    #[test]
+    fn decode_input_heuristic_with_code() {
+        let constructor = constructor();
+        let init_code = constructor.encode_input(code(1), &tokens()).unwrap();
+
+        // Metadata differs, e.g. when compiled at another path.
+        assert_eq!(
+            constructor.decode_input_heuristic(&init_code, Some(&code(2))).unwrap(),
+            tokens()
+        );
+        assert!(constructor.decode_input_heuristic(&init_code, Some(&hex!("6081"))).is_err());
+    }
+
+    #[test]
+    fn decode_input_heuristic_without_code() {
+        let constructor = constructor();
+        let init_code = constructor.encode_input(code(1), &tokens()).unwrap();
+        assert_eq!(constructor.decode_input_heuristic(&init_code, None).unwrap(), tokens());
+
+        // Bytecode without metadata.
+        let init_code = constructor.encode_input(hex!("6080604052").to_vec(), &tokens()).unwrap();
+        assert_eq!(constructor.decode_input_heuristic(&init_code, None).unwrap(), tokens());
+
+        assert!(constructor.decode_input_heuristic(&hex!("6080604052"), None).is_err());
+    }
 }
@@ -6,10 +6,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use alloc::collections::{btree_map::Values, BTreeMap};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec,
+};
 #[cfg(feature = "serde")]
 use core::fmt;
-use core::iter::Flatten;
+use core::slice;
 #[cfg(feature = "full-serde")]
 use std::io;
 
@@ -24,7 +27,10 @@ use serde::{
 use crate::no_std_prelude::*;
 #[cfg(feature = "serde")]
 use crate::operation::Operation;
-use crate::{error::Error as AbiError, errors, Constructor, Error, Event, Function};
+use crate::{
+    error::Error as AbiError, errors, interface_id, Constructor, Error, Event, Fallback, Function,
+    Hash, Receive,
+};
 
 /// API building calls to contracts ABI.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -37,10 +43,56 @@ pub struct Contract {
     pub events: BTreeMap<String, Vec<Event>>,
     /// Contract errors, maps signature to error.
     pub errors: BTreeMap<String, Vec<AbiError>>,
-    /// Contract has receive function.
-    pub receive: bool,
-    /// Contract has fallback function.
-    pub fallback: bool,
+    /// Contract receive function.
+    pub receive: Option<Receive>,
+    /// Contract fallback function.
+    pub fallback: Option<Fallback>,
+    /// Items of the contract in declaration order, each listed at most once.
+    ///
+    /// Items missing from it, e.g. inserted into the maps directly, follow the listed ones in
+    /// arbitrary order.
+    pub items: Vec<Item>,
+}
+
+/// Item of a contract, referring to its entry in the maps of the contract.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Item {
+    /// The constructor.
+    Constructor,
+    /// Function, by name and index among the overloads.
+    Function(String, usize),
+    /// Event, by name and index among the events with that name.
+    Event(String, usize),
+    /// Error, by name and index among the errors with that name.
+    Error(String, usize),
+    /// The receive function.
+    Receive,
+    /// The fallback function.
+    Fallback,
+}
+
+/// Item of a contract, resolved.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+enum OperationRef<'a> {
+    #[cfg_attr(feature = "serde", serde(rename = "constructor"))]
+    Constructor(&'a Constructor),
+
+    #[cfg_attr(feature = "serde", serde(rename = "function"))]
+    Function(&'a Function),
+
+    #[cfg_attr(feature = "serde", serde(rename = "event"))]
+    Event(&'a Event),
+
+    #[cfg_attr(feature = "serde", serde(rename = "error"))]
+    Error(&'a AbiError),
+
+    #[cfg_attr(feature = "serde", serde(rename = "fallback"))]
+    Fallback(&'a Fallback),
+
+    #[cfg_attr(feature = "serde", serde(rename = "receive"))]
+    Receive(&'a Receive),
 }
 
 #[cfg(feature = "serde")]
@@ -73,21 +125,18 @@ impl<'a> Visitor<'a> for ContractVisitor {
             match operation {
                 Operation::Constructor(constructor) => {
                     result.constructor = Some(constructor);
+                    result.items.push(Item::Constructor);
                 }
-                Operation::Function(func) => {
-                    result.functions.entry(func.name.clone()).or_default().push(func);
-                }
-                Operation::Event(event) => {
-                    result.events.entry(event.name.clone()).or_default().push(event);
+                Operation::Function(func) => result.add_function(func),
+                Operation::Event(event) => result.add_event(event),
+                Operation::Error(error) => result.add_error(error),
+                Operation::Fallback(fallback) => {
+                    result.fallback = Some(fallback);
+                    result.items.push(Item::Fallback);
                 }
-                Operation::Error(error) => {
-                    result.errors.entry(error.name.clone()).or_default().push(error);
-                }
-                Operation::Fallback => {
-                    result.fallback = true;
-                }
-                Operation::Receive => {
-                    result.receive = true;
+                Operation::Receive(receive) => {
+                    result.receive = Some(receive);
+                    result.items.push(Item::Receive);
                 }
             }
         }
@@ -102,61 +151,10 @@ impl Serialize for Contract {
     where
         S: Serializer,
     {
-        // Serde's FlatMapSerializer is private, so we'll have to improvise...
-        #[derive(Serialize)]
-        #[serde(tag = "type")]
-        enum OperationRef<'a> {
-            #[serde(rename = "constructor")]
-            Constructor(&'a Constructor),
-
-            #[serde(rename = "function")]
-            Function(&'a Function),
-
-            #[serde(rename = "event")]
-            Event(&'a Event),
-
-            #[serde(rename = "error")]
-            Error(&'a AbiError),
-
-            #[serde(rename = "fallback")]
-            Fallback,
-
-            #[serde(rename = "receive")]
-            Receive,
-        }
-
         let mut seq = serializer.serialize_seq(None)?;
-
-        if let Some(constructor) = &self.constructor {
-            seq.serialize_element(&OperationRef::Constructor(constructor))?;
+        for operation in self.operations() {
+            seq.serialize_element(&operation)?;
         }
-
-        for functions in self.functions.values() {
-            for function in functions {
-                seq.serialize_element(&OperationRef::Function(function))?;
-            }
-        }
-
-        for events in self.events.values() {
-            for event in events {
-                seq.serialize_element(&OperationRef::Event(event))?;
-            }
-        }
-
-        for errors in self.errors.values() {
-            for error in errors {
-                seq.serialize_element(&OperationRef::Error(error))?;
-            }
-        }
-
-        if self.receive {
-            seq.serialize_element(&OperationRef::Receive)?;
-        }
-
-        if self.fallback {
-            seq.serialize_element(&OperationRef::Fallback)?;
-        }
-
         seq.end()
     }
 }
@@ -168,6 +166,27 @@ impl Contract {
         serde_json::from_reader(reader).map_err(From::from)
     }
 
+    /// Adds a function after the other items, as an overload if the name is taken.
+    pub fn add_function(&mut self, function: Function) {
+        let overloads = self.functions.entry(function.name.clone()).or_default();
+        self.items.push(Item::Function(function.name.clone(), overloads.len()));
+        overloads.push(function);
+    }
+
+    /// Adds an event after the other items.
+    pub fn add_event(&mut self, event: Event) {
+        let events = self.events.entry(event.name.clone()).or_default();
+        self.items.push(Item::Event(event.name.clone(), events.len()));
+        events.push(event);
+    }
+
+    /// Adds an error after the other items.
+    pub fn add_error(&mut self, error: AbiError) {
+        let errors = self.errors.entry(error.name.clone()).or_default();
+        self.items.push(Item::Error(error.name.clone(), errors.len()));
+        errors.push(error);
+    }
+
     /// Creates constructor call builder.
     pub fn constructor(&self) -> Option<&Constructor> {
         self.constructor.as_ref()
@@ -219,52 +238,164 @@ impl Contract {
         self.errors.get(name).ok_or_else(|| Error::InvalidName(name.to_owned()))
     }
 
-    /// Iterate over all functions of the contract in arbitrary order.
-    pub fn functions(&self) -> Functions {
-        Functions(self.functions.values().flatten())
+    /// Get the function with the given selector, the first four bytes of its calldata.
+    pub fn function_by_selector(&self, selector: [u8; 4]) -> errors::Result<&Function> {
+        self.functions()
+            .find(|function| function.short_signature() == selector)
+            .ok_or_else(|| Error::InvalidName(hex::encode(selector)))
+    }
+
+    /// Get the non-anonymous event with the given signature, the first topic of its logs.
+    pub fn event_by_signature(&self, signature: &Hash) -> errors::Result<&Event> {
+        self.events()
+            .find(|event| !event.anonymous && event.signature() == *signature)
+            .ok_or_else(|| Error::InvalidName(hex::encode(signature)))
+    }
+
+    /// Returns the ERC-165 interface identifier of all functions of the contract.
+    ///
+    /// Use [`interface_id`](crate::interface_id) for an interface made of a subset of them.
+    pub fn interface_id(&self) -> [u8; 4] {
+        interface_id(self.functions())
+    }
+
+    /// Iterate over all functions of the contract in declaration order.
+    pub fn functions(&self) -> Functions<'_> {
+        Functions(self.operations())
+    }
+
+    /// Iterate over all events of the contract in declaration order.
+    pub fn events(&self) -> Events<'_> {
+        Events(self.operations())
     }
 
-    /// Iterate over all events of the contract in arbitrary order.
-    pub fn events(&self) -> Events {
-        Events(self.events.values().flatten())
+    /// Iterate over all errors of the contract in declaration order.
+    pub fn errors(&self) -> AbiErrors<'_> {
+        AbiErrors(self.operations())
     }
 
-    /// Iterate over all errors of the contract in arbitrary order.
-    pub fn errors(&self) -> AbiErrors {
-        AbiErrors(self.errors.values().flatten())
+    /// Lists all items of the contract in the order of the maps, the constructor first and the
+    /// receive and fallback functions last.
+    pub(crate) fn default_items(&self) -> Vec<Item> {
+        let mut items = Vec::new();
+        items.extend(self.constructor.iter().map(|_| Item::Constructor));
+        for (name, functions) in &self.functions {
+            items.extend((0..functions.len()).map(|i| Item::Function(name.clone(), i)));
+        }
+        for (name, events) in &self.events {
+            items.extend((0..events.len()).map(|i| Item::Event(name.clone(), i)));
+        }
+        for (name, errors) in &self.errors {
+            items.extend((0..errors.len()).map(|i| Item::Error(name.clone(), i)));
+        }
+        items.extend(self.receive.map(|_| Item::Receive));
+        items.extend(self.fallback.map(|_| Item::Fallback));
+        items
+    }
+
+    /// Returns whether `items` lists all items of the maps.
+    fn items_cover_maps(&self) -> bool {
+        let count = usize::from(self.constructor.is_some())
+            + self.functions.values().map(Vec::len).sum::<usize>()
+            + self.events.values().map(Vec::len).sum::<usize>()
+            + self.errors.values().map(Vec::len).sum::<usize>()
+            + usize::from(self.receive.is_some())
+            + usize::from(self.fallback.is_some());
+        self.items.len() == count && self.items.iter().all(|item| self.resolve(item).is_some())
+    }
+
+    /// Resolves the items in declaration order, followed by the items missing from `items`.
+    fn operations(&self) -> Operations<'_> {
+        if self.items_cover_maps() {
+            return Operations::Items(self, self.items.iter());
+        }
+
+        let mut seen = BTreeSet::new();
+        let mut operations = Vec::new();
+        for item in self.items.iter().chain(&self.default_items()) {
+            if seen.contains(item) {
+                continue;
+            }
+            if let Some(operation) = self.resolve(item) {
+                seen.insert(item.clone());
+                operations.push(operation);
+            }
+        }
+        Operations::Resolved(operations.into_iter())
+    }
+
+    fn resolve(&self, item: &Item) -> Option<OperationRef<'_>> {
+        match item {
+            Item::Constructor => self.constructor.as_ref().map(OperationRef::Constructor),
+            Item::Function(name, i) => {
+                self.functions.get(name)?.get(*i).map(OperationRef::Function)
+            }
+            Item::Event(name, i) => self.events.get(name)?.get(*i).map(OperationRef::Event),
+            Item::Error(name, i) => self.errors.get(name)?.get(*i).map(OperationRef::Error),
+            Item::Receive => self.receive.as_ref().map(OperationRef::Receive),
+            Item::Fallback => self.fallback.as_ref().map(OperationRef::Fallback),
+        }
+    }
+}
+
+/// Items of a contract in declaration order.
+enum Operations<'a> {
+    /// Resolves `items` of the contract, which lists all of its items.
+    Items(&'a Contract, slice::Iter<'a, Item>),
+    /// Items resolved ahead, when `items` of the contract misses some.
+    Resolved(vec::IntoIter<OperationRef<'a>>),
+}
+
+impl<'a> Iterator for Operations<'a> {
+    type Item = OperationRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Operations::Items(contract, items) => items.find_map(|item| contract.resolve(item)),
+            Operations::Resolved(operations) => operations.next(),
+        }
     }
 }
 
 /// Contract functions iterator.
-pub struct Functions<'a>(Flatten<Values<'a, String, Vec<Function>>>);
+pub struct Functions<'a>(Operations<'a>);
 
 impl<'a> Iterator for Functions<'a> {
     type Item = &'a Function;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.find_map(|operation| match operation {
+            OperationRef::Function(function) => Some(function),
+            _ => None,
+        })
     }
 }
 
 /// Contract events iterator.
-pub struct Events<'a>(Flatten<Values<'a, String, Vec<Event>>>);
+pub struct Events<'a>(Operations<'a>);
 
 impl<'a> Iterator for Events<'a> {
     type Item = &'a Event;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.find_map(|operation| match operation {
+            OperationRef::Event(event) => Some(event),
+            _ => None,
+        })
     }
 }
 
 /// Contract errors iterator.
-pub struct AbiErrors<'a>(Flatten<Values<'a, String, Vec<AbiError>>>);
+pub struct AbiErrors<'a>(Operations<'a>);
 
 impl<'a> Iterator for AbiErrors<'a> {
     type Item = &'a AbiError;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.find_map(|operation| match operation {
+            OperationRef::Error(error) => Some(error),
+            _ => None,
+        })
     }
 }
 
@@ -276,9 +407,12 @@ mod test {
     use alloc::collections::BTreeMap;
     use core::iter::FromIterator;
 
+    use hex_literal::hex;
+
     use crate::{
-        tests::assert_ser_de, AbiError, Constructor, Contract, Event, EventParam, Function, Param,
-        ParamType,
+        tests::{assert_json_eq, assert_ser_de},
+        AbiError, Constructor, Contract, Event, EventParam, Fallback, Function, Item, Param,
+        ParamType, Receive, StateMutability,
     };
 
     #[test]
@@ -294,8 +428,9 @@ mod test {
                 functions: BTreeMap::new(),
                 events: BTreeMap::new(),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![],
             }
         );
 
@@ -324,17 +459,14 @@ mod test {
             deserialized,
             Contract {
                 constructor: Some(Constructor {
-                    inputs: vec![Param {
-                        name: "a".to_string(),
-                        kind: ParamType::Address,
-                        internal_type: None
-                    }]
+                    inputs: vec![Param::new("a", ParamType::Address)]
                 }),
                 functions: BTreeMap::new(),
                 events: BTreeMap::new(),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![Item::Constructor],
             }
         );
 
@@ -381,16 +513,8 @@ mod test {
                         "foo".to_string(),
                         vec![Function {
                             name: "foo".to_string(),
-                            inputs: vec![Param {
-                                name: "a".to_string(),
-                                kind: ParamType::Address,
-                                internal_type: None,
-                            }],
-                            outputs: vec![Param {
-                                name: "res".to_string(),
-                                kind: ParamType::Address,
-                                internal_type: None,
-                            }],
+                            inputs: vec![Param::new("a", ParamType::Address)],
+                            outputs: vec![Param::new("res", ParamType::Address)],
                             constant: None,
                             state_mutability: Default::default(),
                         }]
@@ -408,8 +532,12 @@ mod test {
                 ]),
                 events: BTreeMap::new(),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![
+                    Item::Function("foo".to_string(), 0),
+                    Item::Function("bar".to_string(), 0)
+                ],
             }
         );
 
@@ -456,16 +584,8 @@ mod test {
                     vec![
                         Function {
                             name: "foo".to_string(),
-                            inputs: vec![Param {
-                                name: "a".to_string(),
-                                kind: ParamType::Address,
-                                internal_type: None,
-                            }],
-                            outputs: vec![Param {
-                                name: "res".to_string(),
-                                kind: ParamType::Address,
-                                internal_type: None,
-                            }],
+                            inputs: vec![Param::new("a", ParamType::Address)],
+                            outputs: vec![Param::new("res", ParamType::Address)],
                             constant: None,
                             state_mutability: Default::default(),
                         },
@@ -480,8 +600,12 @@ mod test {
                 )]),
                 events: BTreeMap::new(),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![
+                    Item::Function("foo".to_string(), 0),
+                    Item::Function("foo".to_string(), 1)
+                ],
             }
         );
 
@@ -530,11 +654,7 @@ mod test {
                         "foo".to_string(),
                         vec![Event {
                             name: "foo".to_string(),
-                            inputs: vec![EventParam {
-                                name: "a".to_string(),
-                                kind: ParamType::Address,
-                                indexed: false,
-                            }],
+                            inputs: vec![EventParam::new("a", ParamType::Address, false)],
                             anonymous: false,
                         }]
                     ),
@@ -542,18 +662,15 @@ mod test {
                         "bar".to_string(),
                         vec![Event {
                             name: "bar".to_string(),
-                            inputs: vec![EventParam {
-                                name: "a".to_string(),
-                                kind: ParamType::Address,
-                                indexed: true
-                            }],
+                            inputs: vec![EventParam::new("a", ParamType::Address, true)],
                             anonymous: false,
                         }]
                     ),
                 ]),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![Item::Event("foo".to_string(), 0), Item::Event("bar".to_string(), 0)],
             }
         );
 
@@ -602,27 +719,20 @@ mod test {
                     vec![
                         Event {
                             name: "foo".to_string(),
-                            inputs: vec![EventParam {
-                                name: "a".to_string(),
-                                kind: ParamType::Address,
-                                indexed: false,
-                            }],
+                            inputs: vec![EventParam::new("a", ParamType::Address, false)],
                             anonymous: false,
                         },
                         Event {
                             name: "foo".to_string(),
-                            inputs: vec![EventParam {
-                                name: "a".to_string(),
-                                kind: ParamType::Address,
-                                indexed: true
-                            }],
+                            inputs: vec![EventParam::new("a", ParamType::Address, true)],
                             anonymous: false,
                         },
                     ]
                 )]),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![Item::Event("foo".to_string(), 0), Item::Event("foo".to_string(), 1)],
             }
         );
 
@@ -678,16 +788,8 @@ mod test {
                         vec![AbiError {
                             name: "foo".to_string(),
                             inputs: vec![
-                                Param {
-                                    name: "available".to_string(),
-                                    kind: ParamType::Uint(256),
-                                    internal_type: None,
-                                },
-                                Param {
-                                    name: "required".to_string(),
-                                    kind: ParamType::Address,
-                                    internal_type: None
-                                }
+                                Param::new("available", ParamType::Uint(256)),
+                                Param::new("required", ParamType::Address)
                             ],
                         }]
                     ),
@@ -696,22 +798,15 @@ mod test {
                         vec![AbiError {
                             name: "bar".to_string(),
                             inputs: vec![
-                                Param {
-                                    name: "a".to_string(),
-                                    kind: ParamType::Uint(256),
-                                    internal_type: None
-                                },
-                                Param {
-                                    name: "b".to_string(),
-                                    kind: ParamType::Address,
-                                    internal_type: None
-                                }
+                                Param::new("a", ParamType::Uint(256)),
+                                Param::new("b", ParamType::Address)
                             ],
                         }]
                     ),
                 ]),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![Item::Error("foo".to_string(), 0), Item::Error("bar".to_string(), 0)],
             }
         );
 
@@ -762,37 +857,107 @@ mod test {
                     vec![
                         AbiError {
                             name: "foo".to_string(),
-                            inputs: vec![Param {
-                                name: "a".to_string(),
-                                kind: ParamType::Uint(256),
-                                internal_type: None,
-                            }],
+                            inputs: vec![Param::new("a", ParamType::Uint(256))],
                         },
                         AbiError {
                             name: "foo".to_string(),
                             inputs: vec![
-                                Param {
-                                    name: "a".to_string(),
-                                    kind: ParamType::Uint(256),
-                                    internal_type: None
-                                },
-                                Param {
-                                    name: "b".to_string(),
-                                    kind: ParamType::Address,
-                                    internal_type: None
-                                }
+                                Param::new("a", ParamType::Uint(256)),
+                                Param::new("b", ParamType::Address)
                             ],
                         },
                     ]
                 ),]),
-                receive: false,
-                fallback: false,
+                receive: None,
+                fallback: None,
+                items: vec![Item::Error("foo".to_string(), 0), Item::Error("foo".to_string(), 1)],
             }
         );
 
         assert_ser_de(&deserialized);
     }
 
+    #[test]
+    fn interface_id() {
+        let json = r#"
+			[
+				{
+					"type": "function",
+					"name": "name",
+					"inputs": [],
+					"outputs": [{ "name": "", "type": "string" }],
+					"stateMutability": "view"
+				},
+				{
+					"type": "function",
+					"name": "symbol",
+					"inputs": [],
+					"outputs": [{ "name": "", "type": "string" }],
+					"stateMutability": "view"
+				},
+				{
+					"type": "function",
+					"name": "tokenURI",
+					"inputs": [{ "name": "tokenId", "type": "uint256" }],
+					"outputs": [{ "name": "", "type": "string" }],
+					"stateMutability": "view"
+				}
+			]
+		"#;
+
+        let contract: Contract = serde_json::from_str(json).unwrap();
+        // ERC721Metadata
+        assert_eq!(contract.interface_id(), hex!("5b5e139f"));
+        assert_eq!(
+            crate::interface_id(contract.functions().filter(|f| f.name != "tokenURI")),
+            hex!("93254542")
+        );
+        assert_eq!(crate::interface_id(Contract::default().functions()), [0u8; 4]);
+    }
+
+    #[test]
+    fn declaration_order() {
+        let json = r#"
+			[
+				{ "type": "function", "name": "foo", "inputs": [], "outputs": [], "stateMutability": "view" },
+				{ "type": "event", "name": "Foo", "inputs": [], "anonymous": false },
+				{ "type": "function", "name": "bar", "inputs": [], "outputs": [], "stateMutability": "view" },
+				{ "type": "receive", "stateMutability": "payable" },
+				{ "type": "error", "name": "Baz", "inputs": [] },
+				{
+					"type": "function",
+					"name": "foo",
+					"inputs": [{ "name": "a", "type": "address" }],
+					"outputs": [],
+					"stateMutability": "view"
+				}
+			]
+		"#;
+
+        let mut deserialized: Contract = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            deserialized.items,
+            vec![
+                Item::Function("foo".to_string(), 0),
+                Item::Event("Foo".to_string(), 0),
+                Item::Function("bar".to_string(), 0),
+                Item::Receive,
+                Item::Error("Baz".to_string(), 0),
+                Item::Function("foo".to_string(), 1),
+            ]
+        );
+        let signatures = deserialized.functions().map(Function::signature).collect::<Vec<_>>();
+        assert_eq!(signatures, ["foo()", "bar()", "foo(address)"]);
+        assert_json_eq(&serde_json::to_string(&deserialized).unwrap(), json);
+
+        // Items missing from the declaration order come last.
+        let qux =
+            Function { name: "qux".to_string(), ..deserialized.function("bar").unwrap().clone() };
+        deserialized.functions.insert("qux".to_string(), vec![qux]);
+        let names = deserialized.functions().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["foo", "bar", "foo", "qux"]);
+    }
+
     #[test]
     fn receive() {
         let json = r#"
@@ -810,8 +975,9 @@ mod test {
                 functions: BTreeMap::new(),
                 events: BTreeMap::new(),
                 errors: BTreeMap::new(),
-                receive: true,
-                fallback: false,
+                receive: Some(Receive { state_mutability: StateMutability::Payable }),
+                fallback: None,
+                items: vec![Item::Receive],
             }
         );
 
@@ -835,8 +1001,9 @@ mod test {
                 functions: BTreeMap::new(),
                 events: BTreeMap::new(),
                 errors: BTreeMap::new(),
-                receive: false,
-                fallback: true,
+                receive: None,
+                fallback: Some(Fallback { state_mutability: StateMutability::NonPayable }),
+                items: vec![Item::Fallback],
             }
         );
 
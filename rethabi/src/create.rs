@@ -0,0 +1,144 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Addresses of contracts deployed with `CREATE` and `CREATE2`.
+
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{Address, Bytes, Hash};
+
+/// Contract deployment, as prepared by [`Constructor::create`](crate::Constructor::create)
+/// and [`Constructor::create2`](crate::Constructor::create2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deployment {
+    /// Bytecode followed by the encoded constructor arguments.
+    pub init_code: Bytes,
+    /// Address the contract will be deployed at.
+    pub address: Address,
+}
+
+/// Returns the address of a contract deployed with `CREATE`, which is taken from the keccak
+/// hash of the RLP encoded sender and nonce.
+pub fn create_address(sender: Address, nonce: u64) -> Address {
+    let nonce_bytes = nonce.to_be_bytes();
+    let nonce_bytes = &nonce_bytes[nonce.leading_zeros() as usize / 8..];
+
+    // RLP list of the 20 byte sender and the nonce, which is always shorter than 56 bytes.
+    let mut stream = Vec::with_capacity(31);
+    let nonce_len = match nonce {
+        0..=0x7f => 1,
+        _ => 1 + nonce_bytes.len(),
+    };
+    stream.push(0xc0 + 21 + nonce_len as u8);
+    stream.push(0x80 + 20);
+    stream.extend_from_slice(sender.as_ref());
+    match nonce {
+        0 => stream.push(0x80),
+        1..=0x7f => stream.push(nonce as u8),
+        _ => {
+            stream.push(0x80 + nonce_bytes.len() as u8);
+            stream.extend_from_slice(nonce_bytes);
+        }
+    }
+
+    address_from_hash(&stream)
+}
+
+/// Returns the address of a contract deployed with `CREATE2`, which is taken from the keccak
+/// hash of `0xff`, the sender, the salt and the keccak hash of the init code.
+pub fn create2_address(sender: Address, salt: Hash, init_code_hash: Hash) -> Address {
+    let mut stream = Vec::with_capacity(85);
+    stream.push(0xff);
+    stream.extend_from_slice(sender.as_ref());
+    stream.extend_from_slice(salt.as_ref());
+    stream.extend_from_slice(init_code_hash.as_ref());
+
+    address_from_hash(&stream)
+}
+
+/// Returns the keccak hash of the init code, as used by [`create2_address`].
+pub fn init_code_hash(init_code: &[u8]) -> Hash {
+    Hash::from_slice(&Keccak256::digest(init_code))
+}
+
+fn address_from_hash(data: &[u8]) -> Address {
+    Address::from_slice(&Keccak256::digest(data)[12..])
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{create2_address, create_address, init_code_hash};
+    use crate::{Address, Constructor, Hash, Param, ParamType, Token};
+
+    #[test]
+    fn test_create_address() {
+        let sender = Address::from(hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"));
+        assert_eq!(
+            create_address(sender, 0),
+            Address::from(hex!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"))
+        );
+        assert_eq!(
+            create_address(sender, 1),
+            Address::from(hex!("343c43a37d37dff08ae8c4a11544c718abb4fcf8"))
+        );
+        assert_eq!(
+            create_address(sender, 2),
+            Address::from(hex!("f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"))
+        );
+    }
+
+    #[test]
+    fn test_create2_address() {
+        // Examples from EIP-1014.
+        assert_eq!(
+            create2_address([0u8; 20].into(), Hash::zero(), init_code_hash(&hex!("00"))),
+            Address::from(hex!("4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"))
+        );
+        assert_eq!(
+            create2_address(
+                hex!("deadbeef00000000000000000000000000000000").into(),
+                Hash::zero(),
+                init_code_hash(&hex!("00"))
+            ),
+            Address::from(hex!("b928f69bb1d91cd65274e3c79d8986362984fda3"))
+        );
+        assert_eq!(
+            create2_address(
+                hex!("00000000000000000000000000000000deadbeef").into(),
+                hex!("00000000000000000000000000000000000000000000000000000000cafebabe").into(),
+                init_code_hash(&hex!("deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"))
+            ),
+            Address::from(hex!("1d8bfdc5d46dc4f61d6b6115972536ebe6a8854c"))
+        );
+    }
+
+    #[test]
+    fn test_constructor_create() {
+        let constructor = Constructor { inputs: vec![Param::new("a", ParamType::Bool)] };
+        let sender = Address::from(hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"));
+        let code = hex!("6080").to_vec();
+        let tokens = [Token::Bool(true)];
+        let init_code = constructor.encode_input(code.clone(), &tokens).unwrap();
+
+        let deployment = constructor.create(code.clone(), &tokens, sender, 1).unwrap();
+        assert_eq!(deployment.init_code, init_code);
+        assert_eq!(deployment.address, create_address(sender, 1));
+
+        let salt =
+            Hash::from(hex!("00000000000000000000000000000000000000000000000000000000cafebabe"));
+        let deployment = constructor.create2(code.clone(), &tokens, sender, salt).unwrap();
+        assert_eq!(deployment.address, create2_address(sender, salt, init_code_hash(&init_code)));
+        assert_eq!(deployment.init_code, init_code);
+
+        assert!(constructor.create(code, &[], sender, 1).is_err());
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decoded function inputs and outputs.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{Param, Token, TupleParam};
+
+/// Decoded function param.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct DecodedParam {
+    /// Param name, empty if the param is unnamed, or index of an array element.
+    pub name: String,
+    /// Decoded value.
+    pub value: Token,
+    /// Decoded components of a tuple value, named after the tuple components of the param, or
+    /// elements of an array value, named after their index.
+    ///
+    /// Empty for other values, and for tuples if the names of the components are unknown.
+    pub components: Vec<DecodedParam>,
+}
+
+impl DecodedParam {
+    fn new(name: String, value: Token, components: &[TupleParam]) -> Self {
+        let decoded = match &value {
+            Token::Tuple(tokens) => tokens
+                .iter()
+                .zip(components)
+                .map(|(token, component)| {
+                    let name = component.name.clone().unwrap_or_default();
+                    DecodedParam::new(name, token.clone(), component.named_components())
+                })
+                .collect(),
+            // The components of the param describe the tuples in the array.
+            Token::Array(tokens) | Token::FixedArray(tokens) => tokens
+                .iter()
+                .enumerate()
+                .map(|(i, token)| DecodedParam::new(i.to_string(), token.clone(), components))
+                .collect(),
+            _ => vec![],
+        };
+        DecodedParam { name, value, components: decoded }
+    }
+
+    /// Returns the tuple component with the given name, or the array element with the given
+    /// index.
+    pub fn component(&self, name: &str) -> Option<&DecodedParam> {
+        self.components.iter().find(|component| component.name == name)
+    }
+}
+
+/// Decoded function inputs or outputs.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Decoded {
+    /// Decoded params.
+    pub params: Vec<DecodedParam>,
+}
+
+impl Decoded {
+    pub(crate) fn new(params: &[Param], tokens: Vec<Token>) -> Self {
+        let params = params
+            .iter()
+            .zip(tokens)
+            .map(|(param, token)| {
+                DecodedParam::new(param.name.clone(), token, param.named_components())
+            })
+            .collect();
+        Decoded { params }
+    }
+
+    /// Returns the param at `path`, the name of a param optionally followed by names of
+    /// tuple components and indices of array elements separated by dots, e.g. `orders.0.maker`.
+    pub fn param(&self, path: &str) -> Option<&DecodedParam> {
+        let mut names = path.split('.');
+        let first = names.next()?;
+        let param = self.params.iter().find(|param| param.name == first)?;
+        names.try_fold(param, |param, name| param.component(name))
+    }
+
+    /// Returns the value of the param at `path`, see [`Decoded::param`].
+    pub fn get(&self, path: &str) -> Option<&Token> {
+        self.param(path).map(|param| &param.value)
+    }
+
+    /// Returns the decoded values in order, as returned by `Function::decode_output`.
+    pub fn into_tokens(self) -> Vec<Token> {
+        self.params.into_iter().map(|param| param.value).collect()
+    }
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use hex_literal::hex;
+
+    use crate::{Address, Function, Token, Uint};
+
+    #[test]
+    fn decode_input_named() {
+        let function: Function = serde_json::from_str(
+            r#"{
+			"type": "function",
+			"name": "fill",
+			"inputs": [
+				{
+					"name": "orders",
+					"type": "tuple[]",
+					"components": [
+						{ "name": "maker", "type": "address" },
+						{ "name": "amount", "type": "uint256" }
+					]
+				},
+				{ "name": "ids", "type": "uint256[2]" }
+			],
+			"outputs": [],
+			"stateMutability": "nonpayable"
+		}"#,
+        )
+        .unwrap();
+        let order = |maker: u8, amount: u64| {
+            Token::Tuple(vec![
+                Token::Address(Address::from([maker; 20])),
+                Token::Uint(Uint::from(amount)),
+            ])
+        };
+        let tokens = vec![
+            Token::Array(vec![order(0x11, 1), order(0x22, 2)]),
+            Token::FixedArray(vec![Token::Uint(Uint::from(7)), Token::Uint(Uint::from(8))]),
+        ];
+        let data = function.encode_input(&tokens).unwrap();
+
+        let decoded = function.decode_input_named(&data[4..]).unwrap();
+        assert_eq!(decoded.get("orders.1.maker"), Some(&Token::Address(Address::from([0x22; 20]))));
+        assert_eq!(decoded.get("orders.0.amount"), Some(&Token::Uint(Uint::from(1))));
+        assert_eq!(decoded.get("orders.0"), Some(&order(0x11, 1)));
+        assert_eq!(decoded.get("orders.2"), None);
+        assert_eq!(decoded.get("ids.1"), Some(&Token::Uint(Uint::from(8))));
+        assert_eq!(decoded.param("orders").unwrap().components.len(), 2);
+        assert_eq!(decoded.into_tokens(), tokens);
+    }
+
+    #[test]
+    fn decode_output_named() {
+        let function: Function = serde_json::from_str(
+            r#"{
+			"type": "function",
+			"name": "order",
+			"inputs": [],
+			"outputs": [
+				{
+					"name": "order",
+					"type": "tuple",
+					"components": [
+						{ "name": "maker", "type": "address" },
+						{
+							"name": "fill",
+							"type": "tuple",
+							"components": [{ "name": "amount", "type": "uint256" }]
+						}
+					]
+				},
+				{ "name": "", "type": "bool" }
+			],
+			"stateMutability": "view"
+		}"#,
+        )
+        .unwrap();
+        let data = hex!(
+            "
+			0000000000000000000000001111111111111111111111111111111111111111
+			000000000000000000000000000000000000000000000000000000000000002a
+			0000000000000000000000000000000000000000000000000000000000000001
+		"
+        );
+
+        let maker = Token::Address(Address::from([0x11; 20]));
+        let amount = Token::Uint(Uint::from(42));
+        let decoded = function.decode_output_named(&data).unwrap();
+        assert_eq!(decoded.get("order.maker"), Some(&maker));
+        assert_eq!(decoded.get("order.fill.amount"), Some(&amount));
+        assert_eq!(decoded.get("order.fill.price"), None);
+        assert_eq!(decoded.param("order").unwrap().components.len(), 2);
+        assert_eq!(decoded.params[1].name, "");
+        assert_eq!(decoded.into_tokens(), function.decode_output(&data).unwrap());
+    }
+}
@@ -149,6 +149,37 @@ fn decode_param(
                 DecodeResult { token: Token::Uint(array_to_u256(slice)), new_offset: offset + 32 };
             Ok(result)
         }
+        ParamType::Fixed(_, decimals) => {
+            let slice = peek_32_bytes(data, offset)?;
+            let result = DecodeResult {
+                token: Token::Fixed(array_to_u256(slice), decimals),
+                new_offset: offset + 32,
+            };
+            Ok(result)
+        }
+        ParamType::Ufixed(_, decimals) => {
+            let slice = peek_32_bytes(data, offset)?;
+            let result = DecodeResult {
+                token: Token::Ufixed(array_to_u256(slice), decimals),
+                new_offset: offset + 32,
+            };
+            Ok(result)
+        }
+        ParamType::Function => {
+            let slice = peek_32_bytes(data, offset)?;
+            if validate {
+                check_zeroes(&slice[24..])?;
+            }
+            let mut address = [0u8; 20];
+            address.copy_from_slice(&slice[..20]);
+            let mut selector = [0u8; 4];
+            selector.copy_from_slice(&slice[20..24]);
+            let result = DecodeResult {
+                token: Token::Function(address.into(), selector),
+                new_offset: offset + 32,
+            };
+            Ok(result)
+        }
         ParamType::Bool => {
             let b = as_bool(&peek_32_bytes(data, offset)?)?;
             let result = DecodeResult { token: Token::Bool(b), new_offset: offset + 32 };
@@ -677,22 +708,17 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
             Function {
                 name: "f_tuple".to_string(),
                 inputs: vec![
-                    Param {
-                        name: "c".to_string(),
-                        kind: Array(Box::new(Tuple(vec![Uint(256), Uint(256)]))),
-                        internal_type: None,
-                    },
-                    Param {
-                        name: "d".to_string(),
-                        kind: Array(Box::new(Tuple(vec![
+                    Param::new("c", Array(Box::new(Tuple(vec![Uint(256), Uint(256)])))),
+                    Param::new(
+                        "d",
+                        Array(Box::new(Tuple(vec![
                             Uint(256),
                             Array(Box::new(Tuple(vec![
                                 Uint(256),
                                 Array(Box::new(ParamType::String)),
                             ]))),
                         ]))),
-                        internal_type: None,
-                    },
+                    ),
                 ],
                 outputs: vec![],
                 constant: None,
@@ -724,12 +750,8 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
             Function {
                 name: "f".to_string(),
                 inputs: vec![
-                    Param { name: "i".to_string(), kind: Uint(256), internal_type: None },
-                    Param {
-                        name: "p".to_string(),
-                        kind: FixedArray(Box::new(ParamType::String), 2),
-                        internal_type: None,
-                    },
+                    Param::new("i", Uint(256)),
+                    Param::new("p", FixedArray(Box::new(ParamType::String), 2)),
                 ],
                 outputs: vec![],
                 constant: None,
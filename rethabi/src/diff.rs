@@ -0,0 +1,413 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compatibility of two versions of a contract.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    error::Error as AbiError, signature::signature_string, Contract, Event, Function, ParamType,
+    StateMutability,
+};
+
+/// Kind of change between two versions of a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    /// The item was added.
+    Added,
+    /// The item was removed.
+    Removed,
+    /// The output types of a function changed.
+    OutputsChanged {
+        /// Output types of the old version.
+        old: Vec<ParamType>,
+        /// Output types of the new version.
+        new: Vec<ParamType>,
+    },
+    /// The state mutability of a function or of the fallback function changed.
+    StateMutabilityChanged {
+        /// State mutability of the old version.
+        old: StateMutability,
+        /// State mutability of the new version.
+        new: StateMutability,
+    },
+    /// Whether an event param is indexed changed.
+    IndexedChanged {
+        /// Name of the param.
+        param: String,
+        /// Whether the param is indexed in the new version.
+        indexed: bool,
+    },
+    /// Whether an event is anonymous changed.
+    AnonymousChanged {
+        /// Whether the event is anonymous in the new version.
+        anonymous: bool,
+    },
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChangeKind::Added => write!(f, "added"),
+            ChangeKind::Removed => write!(f, "removed"),
+            ChangeKind::OutputsChanged { old, new } => write!(
+                f,
+                "outputs changed from {} to {}",
+                signature_string("", old),
+                signature_string("", new)
+            ),
+            ChangeKind::StateMutabilityChanged { old, new } => {
+                write!(f, "state mutability changed from {old} to {new}")
+            }
+            ChangeKind::IndexedChanged { param, indexed: true } => {
+                write!(f, "param `{param}` is now indexed")
+            }
+            ChangeKind::IndexedChanged { param, indexed: false } => {
+                write!(f, "param `{param}` is no longer indexed")
+            }
+            ChangeKind::AnonymousChanged { anonymous: true } => write!(f, "is now anonymous"),
+            ChangeKind::AnonymousChanged { anonymous: false } => {
+                write!(f, "is no longer anonymous")
+            }
+        }
+    }
+}
+
+/// Kind of item that changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Item {
+    /// Function, including the receive and fallback functions.
+    Function,
+    /// Event.
+    Event,
+    /// Error.
+    Error,
+}
+
+/// Change between two versions of a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Item that changed, e.g. `function transfer(address,uint256)`.
+    pub item: String,
+    /// What changed.
+    pub kind: ChangeKind,
+    /// Whether callers or indexers built against the old version break.
+    pub breaking: bool,
+}
+
+impl Change {
+    fn new(item_kind: Item, item: &str, kind: ChangeKind) -> Self {
+        let breaking = match &kind {
+            ChangeKind::Added => false,
+            ChangeKind::Removed => match item_kind {
+                Item::Function | Item::Event => true,
+                Item::Error => false,
+            },
+            ChangeKind::StateMutabilityChanged { old, new } => is_breaking_mutability(*old, *new),
+            ChangeKind::OutputsChanged { .. }
+            | ChangeKind::IndexedChanged { .. }
+            | ChangeKind::AnonymousChanged { .. } => true,
+        };
+        Change { item: item.to_owned(), kind, breaking }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let compatibility = if self.breaking { "breaking" } else { "compatible" };
+        write!(f, "{}: {}: {}", compatibility, self.item, self.kind)
+    }
+}
+
+/// Calls to `view` and `pure` functions may be static calls, which fail once the function
+/// modifies state, and calls sending ether fail once the function is no longer `payable`.
+fn is_breaking_mutability(old: StateMutability, new: StateMutability) -> bool {
+    use StateMutability::*;
+
+    matches!((old, new), (Pure | View, NonPayable | Payable) | (Payable, Pure | View | NonPayable))
+}
+
+impl Contract {
+    /// Lists the changes from this version of the contract to `new`, matching functions and
+    /// errors by selector and events by signature.
+    ///
+    /// Removed functions and events, changed function outputs and event topics, and state
+    /// mutability changes that reject former calls are breaking. Removed errors are not, as
+    /// they are only ever returned by the contract.
+    pub fn diff(&self, new: &Contract) -> Vec<Change> {
+        let mut changes = Vec::new();
+
+        for old_function in self.functions() {
+            let item = function_item(old_function);
+            let selector = old_function.short_signature();
+            match new.functions().find(|function| function.short_signature() == selector) {
+                Some(new_function) => {
+                    diff_function(&mut changes, &item, old_function, new_function)
+                }
+                None => changes.push(Change::new(Item::Function, &item, ChangeKind::Removed)),
+            }
+        }
+        for new_function in new.functions() {
+            let selector = new_function.short_signature();
+            if !self.functions().any(|function| function.short_signature() == selector) {
+                changes.push(Change::new(
+                    Item::Function,
+                    &function_item(new_function),
+                    ChangeKind::Added,
+                ));
+            }
+        }
+
+        for old_event in self.events() {
+            let item = event_item(old_event);
+            let signature = old_event.signature();
+            match new.events().find(|event| event.signature() == signature) {
+                Some(new_event) => diff_event(&mut changes, &item, old_event, new_event),
+                None => changes.push(Change::new(Item::Event, &item, ChangeKind::Removed)),
+            }
+        }
+        for new_event in new.events() {
+            let signature = new_event.signature();
+            if !self.events().any(|event| event.signature() == signature) {
+                changes.push(Change::new(Item::Event, &event_item(new_event), ChangeKind::Added));
+            }
+        }
+
+        for old_error in self.errors() {
+            let signature = old_error.signature();
+            if !new.errors().any(|error| error.signature() == signature) {
+                changes.push(Change::new(Item::Error, &error_item(old_error), ChangeKind::Removed));
+            }
+        }
+        for new_error in new.errors() {
+            let signature = new_error.signature();
+            if !self.errors().any(|error| error.signature() == signature) {
+                changes.push(Change::new(Item::Error, &error_item(new_error), ChangeKind::Added));
+            }
+        }
+
+        for (item, old, new) in [
+            (
+                "receive",
+                self.receive.map(|r| r.state_mutability),
+                new.receive.map(|r| r.state_mutability),
+            ),
+            (
+                "fallback",
+                self.fallback.map(|f| f.state_mutability),
+                new.fallback.map(|f| f.state_mutability),
+            ),
+        ] {
+            match (old, new) {
+                (Some(_), None) => {
+                    changes.push(Change::new(Item::Function, item, ChangeKind::Removed))
+                }
+                (None, Some(_)) => {
+                    changes.push(Change::new(Item::Function, item, ChangeKind::Added))
+                }
+                (Some(old), Some(new)) if old != new => changes.push(Change::new(
+                    Item::Function,
+                    item,
+                    ChangeKind::StateMutabilityChanged { old, new },
+                )),
+                _ => {}
+            }
+        }
+
+        changes
+    }
+}
+
+fn diff_function(changes: &mut Vec<Change>, item: &str, old: &Function, new: &Function) {
+    let old_outputs = old.outputs.iter().map(|p| p.kind.clone()).collect::<Vec<_>>();
+    let new_outputs = new.outputs.iter().map(|p| p.kind.clone()).collect::<Vec<_>>();
+    if old_outputs != new_outputs {
+        changes.push(Change::new(
+            Item::Function,
+            item,
+            ChangeKind::OutputsChanged { old: old_outputs, new: new_outputs },
+        ));
+    }
+
+    if old.state_mutability != new.state_mutability {
+        changes.push(Change::new(
+            Item::Function,
+            item,
+            ChangeKind::StateMutabilityChanged {
+                old: old.state_mutability,
+                new: new.state_mutability,
+            },
+        ));
+    }
+}
+
+fn diff_event(changes: &mut Vec<Change>, item: &str, old: &Event, new: &Event) {
+    if old.anonymous != new.anonymous {
+        changes.push(Change::new(
+            Item::Event,
+            item,
+            ChangeKind::AnonymousChanged { anonymous: new.anonymous },
+        ));
+    }
+
+    // Same signature, so both have the same number of params.
+    for (old_param, new_param) in old.inputs.iter().zip(&new.inputs) {
+        if old_param.indexed != new_param.indexed {
+            changes.push(Change::new(
+                Item::Event,
+                item,
+                ChangeKind::IndexedChanged {
+                    param: new_param.name.clone(),
+                    indexed: new_param.indexed,
+                },
+            ));
+        }
+    }
+}
+
+pub(crate) fn function_item(function: &Function) -> String {
+    format!("function {}", signature_string(&function.name, &function.inputs))
+}
+
+pub(crate) fn event_item(event: &Event) -> String {
+    format!("event {}", signature_string(&event.name, &event.inputs))
+}
+
+pub(crate) fn error_item(error: &AbiError) -> String {
+    format!("error {}", signature_string(&error.name, &error.inputs))
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use crate::{ChangeKind, Contract, ParamType, StateMutability};
+
+    fn contract(abi: &str) -> Contract {
+        serde_json::from_str(abi).unwrap()
+    }
+
+    const OLD: &str = r#"[
+		{
+			"type": "function",
+			"name": "balanceOf",
+			"inputs": [{ "name": "owner", "type": "address" }],
+			"outputs": [{ "name": "", "type": "uint256" }],
+			"stateMutability": "view"
+		},
+		{
+			"type": "function",
+			"name": "deposit",
+			"inputs": [],
+			"outputs": [],
+			"stateMutability": "payable"
+		},
+		{
+			"type": "function",
+			"name": "burn",
+			"inputs": [{ "name": "amount", "type": "uint256" }],
+			"outputs": [],
+			"stateMutability": "nonpayable"
+		},
+		{
+			"type": "event",
+			"name": "Transfer",
+			"inputs": [
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "value", "type": "uint256", "indexed": false }
+			],
+			"anonymous": false
+		},
+		{ "type": "error", "name": "Paused", "inputs": [] },
+		{ "type": "receive", "stateMutability": "payable" },
+		{ "type": "fallback", "stateMutability": "nonpayable" }
+	]"#;
+
+    const NEW: &str = r#"[
+		{
+			"type": "function",
+			"name": "balanceOf",
+			"inputs": [{ "name": "account", "type": "address" }],
+			"outputs": [{ "name": "", "type": "uint128" }],
+			"stateMutability": "nonpayable"
+		},
+		{
+			"type": "function",
+			"name": "deposit",
+			"inputs": [],
+			"outputs": [],
+			"stateMutability": "nonpayable"
+		},
+		{
+			"type": "function",
+			"name": "mint",
+			"inputs": [{ "name": "amount", "type": "uint256" }],
+			"outputs": [],
+			"stateMutability": "nonpayable"
+		},
+		{
+			"type": "event",
+			"name": "Transfer",
+			"inputs": [
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "value", "type": "uint256", "indexed": true }
+			],
+			"anonymous": false
+		},
+		{ "type": "error", "name": "Unauthorized", "inputs": [] },
+		{ "type": "fallback", "stateMutability": "payable" }
+	]"#;
+
+    #[test]
+    fn diff() {
+        let changes = contract(OLD).diff(&contract(NEW));
+        let lines = changes.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "breaking: function balanceOf(address): outputs changed from (uint256) to (uint128)",
+                "breaking: function balanceOf(address): state mutability changed from view to nonpayable",
+                "breaking: function deposit(): state mutability changed from payable to nonpayable",
+                "breaking: function burn(uint256): removed",
+                "compatible: function mint(uint256): added",
+                "breaking: event Transfer(address,uint256): param `value` is now indexed",
+                "compatible: error Paused(): removed",
+                "compatible: error Unauthorized(): added",
+                "breaking: receive: removed",
+                "compatible: fallback: state mutability changed from nonpayable to payable",
+            ]
+        );
+
+        assert_eq!(
+            changes[1].kind,
+            ChangeKind::StateMutabilityChanged {
+                old: StateMutability::View,
+                new: StateMutability::NonPayable
+            }
+        );
+        assert_eq!(
+            changes[0].kind,
+            ChangeKind::OutputsChanged {
+                old: vec![ParamType::Uint(256)],
+                new: vec![ParamType::Uint(128)]
+            }
+        );
+    }
+
+    #[test]
+    fn diff_compatible() {
+        let old = contract(OLD);
+        assert!(old.diff(&old).is_empty());
+
+        let new =
+            contract(&OLD.replace(r#""stateMutability": "view""#, r#""stateMutability": "pure""#));
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 1);
+        assert!(!changes[0].breaking);
+    }
+}
@@ -139,13 +139,14 @@ fn encode_head_tail_append(acc: &mut Vec<Word>, mediates: &[Mediate]) {
     mediates.iter().for_each(|m| m.tail_append(acc));
 }
 
-fn mediate_token(token: &Token) -> Mediate {
+fn mediate_token(token: &Token) -> Mediate<'_> {
     match token {
         Token::Address(_) => Mediate::Raw(1, token),
         Token::Bytes(bytes) => Mediate::Prefixed(pad_bytes_len(bytes), token),
         Token::String(s) => Mediate::Prefixed(pad_bytes_len(s.as_bytes()), token),
         Token::FixedBytes(bytes) => Mediate::Raw(fixed_bytes_len(bytes), token),
         Token::Int(_) | Token::Uint(_) | Token::Bool(_) => Mediate::Raw(1, token),
+        Token::Fixed(..) | Token::Ufixed(..) | Token::Function(..) => Mediate::Raw(1, token),
         Token::Array(ref tokens) => {
             let mediates = tokens.iter().map(mediate_token).collect();
 
@@ -175,6 +176,14 @@ fn encode_token_append(data: &mut Vec<Word>, token: &Token) {
         Token::FixedBytes(ref bytes) => fixed_bytes_append(data, bytes),
         Token::Int(int) => data.push(int.to_be_bytes()),
         Token::Uint(uint) => data.push(uint.to_be_bytes()),
+        Token::Fixed(int, _) => data.push(int.to_be_bytes()),
+        Token::Ufixed(uint, _) => data.push(uint.to_be_bytes()),
+        Token::Function(ref address, ref selector) => {
+            let mut padded = [0u8; 32];
+            padded[..20].copy_from_slice(address.as_ref());
+            padded[20..24].copy_from_slice(selector);
+            data.push(padded);
+        }
         Token::Bool(b) => {
             let mut value = [0u8; 32];
             if b {
@@ -621,7 +630,7 @@ mod tests {
 
     #[test]
     fn comprehensive_test2() {
-        let encoded = encode(&vec![
+        let encoded = encode(&[
             Token::Int(Uint::from(1)),
             Token::String("gavofyork".to_owned()),
             Token::Int(Uint::from(2)),
@@ -64,14 +64,7 @@ impl Event {
             if !token.type_check(kind) {
                 return Err(Error::InvalidData);
             }
-            let encoded = encode(&[token]);
-            if encoded.len() == 32 {
-                let mut data = [0u8; 32];
-                data.copy_from_slice(&encoded);
-                Ok(data.into())
-            } else {
-                Ok(Hash::from_slice(Keccak256::digest(&encoded).as_slice()))
-            }
+            Ok(encode_topic(&token))
         }
 
         fn convert_topic(topic: Topic<Token>, kind: Option<&ParamType>) -> Result<Topic<Hash>> {
@@ -94,39 +87,47 @@ impl Event {
             }
         }
 
-        let kinds: Vec<_> = self.indexed_params(true).into_iter().map(|param| param.kind).collect();
-        let result = if self.anonymous {
-            TopicFilter {
-                topic0: convert_topic(raw.topic0, kinds.get(0))?,
-                topic1: convert_topic(raw.topic1, kinds.get(1))?,
-                topic2: convert_topic(raw.topic2, kinds.get(2))?,
-                topic3: Topic::Any,
-            }
-        } else {
-            TopicFilter {
-                topic0: Topic::This(self.signature()),
-                topic1: convert_topic(raw.topic0, kinds.get(0))?,
-                topic2: convert_topic(raw.topic1, kinds.get(1))?,
-                topic3: convert_topic(raw.topic2, kinds.get(2))?,
-            }
-        };
+        let kinds = self.topic_kinds();
+        let kinds = kinds.iter().filter_map(TopicKind::param_type).collect::<Vec<_>>();
+        let raw = [raw.topic0, raw.topic1, raw.topic2];
+        let mut topics = self.topic0().map(Topic::This).into_iter().collect::<Vec<_>>();
+        for (i, topic) in raw.into_iter().enumerate() {
+            topics.push(convert_topic(topic, kinds.get(i).copied())?);
+        }
 
-        Ok(result)
+        let mut topics = topics.into_iter();
+        let mut next = || topics.next().unwrap_or(Topic::Any);
+        Ok(TopicFilter { topic0: next(), topic1: next(), topic2: next(), topic3: next() })
+    }
+
+    /// Returns the first topic of logs of the event, its signature, unless it is anonymous.
+    pub fn topic0(&self) -> Option<Hash> {
+        (!self.anonymous).then(|| self.signature())
+    }
+
+    /// Returns the number of topics of logs of the event.
+    pub fn topic_count(&self) -> usize {
+        usize::from(!self.anonymous) + self.inputs.iter().filter(|p| p.indexed).count()
     }
 
-    // Converts param types for indexed parameters to bytes32 where appropriate
-    // This applies to strings, arrays, structs and bytes to follow the encoding of
-    // these indexed param types according to
-    // https://solidity.readthedocs.io/en/develop/abi-spec.html#encoding-of-indexed-event-parameters
-    fn convert_topic_param_type(&self, kind: &ParamType) -> ParamType {
-        match kind {
+    /// Returns what each topic of logs of the event holds.
+    pub fn topic_kinds(&self) -> Vec<TopicKind> {
+        let params = self.inputs.iter().filter(|p| p.indexed).map(|p| match p.kind {
             ParamType::String
             | ParamType::Bytes
             | ParamType::Array(_)
             | ParamType::FixedArray(_, _)
-            | ParamType::Tuple(_) => ParamType::FixedBytes(32),
-            _ => kind.clone(),
-        }
+            | ParamType::Tuple(_) => TopicKind::Hash(p.kind.clone()),
+            _ => TopicKind::Value(p.kind.clone()),
+        });
+        self.topic0().map(|_| TopicKind::Signature).into_iter().chain(params).collect()
+    }
+
+    /// Checks whether topics of a log have the number of topics of the event, starting with its
+    /// signature unless it is anonymous.
+    pub fn matches_topics(&self, topics: &[Hash]) -> bool {
+        topics.len() == self.topic_count()
+            && self.topic0().map_or(true, |topic0| topics.first() == Some(&topic0))
     }
 
     fn parse_log_inner<F: Fn(&[ParamType], &[u8]) -> Result<Vec<Token>>>(
@@ -136,54 +137,52 @@ impl Event {
     ) -> Result<Log> {
         let topics = log.topics;
         let data = log.data;
-        let topics_len = topics.len();
+        if !self.matches_topics(&topics) {
+            return Err(Error::InvalidData);
+        }
         // obtains all params info
         let topic_params = self.indexed_params(true);
         let data_params = self.indexed_params(false);
-        // then take first topic if event is not anonymous
-        let to_skip = if self.anonymous {
-            0
-        } else {
-            // verify
-            let event_signature = topics.get(0).ok_or(Error::InvalidData)?;
-            if event_signature != &self.signature() {
-                return Err(Error::InvalidData);
-            }
-            1
-        };
-
-        let topic_types = topic_params
-            .iter()
-            .map(|p| self.convert_topic_param_type(&p.kind))
-            .collect::<Vec<ParamType>>();
-
+        // hashed values are decoded as their hash
+        let (topic_types, hashed): (Vec<_>, Vec<_>) = self
+            .topic_kinds()
+            .into_iter()
+            .filter_map(|kind| match kind {
+                TopicKind::Signature => None,
+                TopicKind::Value(kind) => Some((kind, None)),
+                TopicKind::Hash(kind) => Some((ParamType::FixedBytes(32), Some(kind))),
+            })
+            .unzip();
+
+        let to_skip = topics.len() - topic_types.len();
         let flat_topics =
             topics.into_iter().skip(to_skip).flat_map(|t| t.0.to_vec()).collect::<Vec<u8>>();
 
         let topic_tokens = decode(&topic_types, &flat_topics)?;
 
-        // topic may be only a 32 bytes encoded token
-        if topic_tokens.len() != topics_len - to_skip {
-            return Err(Error::InvalidData);
-        }
-
-        let topics_named_tokens =
-            topic_params.into_iter().map(|p| p.name).zip(topic_tokens.into_iter());
+        let topics_named_tokens = topic_params
+            .into_iter()
+            .zip(topic_tokens.into_iter().zip(hashed))
+            .map(|(p, token)| (p.name, token));
 
         let data_types = data_params.iter().map(|p| p.kind.clone()).collect::<Vec<ParamType>>();
 
         let data_tokens = decode(&data_types, &data)?;
 
         let data_named_tokens =
-            data_params.into_iter().map(|p| p.name).zip(data_tokens.into_iter());
+            data_params.into_iter().map(|p| p.name).zip(data_tokens.into_iter().map(|t| (t, None)));
 
-        let named_tokens =
-            topics_named_tokens.chain(data_named_tokens).collect::<BTreeMap<String, Token>>();
+        let named_tokens = topics_named_tokens
+            .chain(data_named_tokens)
+            .collect::<BTreeMap<String, (Token, Option<ParamType>)>>();
 
         let decoded_params = self
             .params_names()
             .into_iter()
-            .map(|name| LogParam { name: name.clone(), value: named_tokens[&name].clone() })
+            .map(|name| {
+                let (value, hashed) = named_tokens[&name].clone();
+                LogParam { name, value, hashed }
+            })
             .collect();
 
         let result = Log { params: decoded_params };
@@ -201,11 +200,94 @@ impl Event {
     pub fn parse_log(&self, log: RawLog) -> Result<Log> {
         self.parse_log_inner(log, decode)
     }
+
+    /// Encodes the tokens of all params, in declaration order, to a `RawLog`.
+    ///
+    /// Indexed params become topics, after the signature unless the event is anonymous, with
+    /// strings, bytes, arrays and tuples replaced by their hash. The other params are encoded
+    /// to the data of the log.
+    pub fn encode_log(&self, tokens: &[Token]) -> Result<RawLog> {
+        if tokens.len() != self.inputs.len() || !Token::types_check(tokens, &self.param_types()) {
+            return Err(Error::InvalidData);
+        }
+
+        let mut topics = self.topic0().into_iter().collect::<Vec<_>>();
+        let mut data_tokens = Vec::new();
+        for (param, token) in self.inputs.iter().zip(tokens) {
+            if param.indexed {
+                topics.push(encode_topic(token));
+            } else {
+                data_tokens.push(token.clone());
+            }
+        }
+
+        Ok(RawLog { topics, data: encode(&data_tokens) })
+    }
+}
+
+/// What a topic of an event log holds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopicKind {
+    /// The signature of the event.
+    Signature,
+    /// The value of an indexed param of this type.
+    Value(ParamType),
+    /// The hash of the value of an indexed string, bytes, array or tuple param of this type.
+    Hash(ParamType),
+}
+
+impl TopicKind {
+    /// Returns the type of the indexed param the topic holds.
+    pub fn param_type(&self) -> Option<&ParamType> {
+        match self {
+            TopicKind::Signature => None,
+            TopicKind::Value(kind) | TopicKind::Hash(kind) => Some(kind),
+        }
+    }
+}
+
+/// Encodes the value of an indexed param to a topic.
+///
+/// Strings and bytes are hashed without padding or length, arrays and tuples are hashed in
+/// their in-place encoding, see
+/// https://docs.soliditylang.org/en/latest/abi-spec.html#encoding-of-indexed-event-parameters
+pub(crate) fn encode_topic(token: &Token) -> Hash {
+    match token {
+        Token::String(string) => Hash::from_slice(&Keccak256::digest(string.as_bytes())),
+        Token::Bytes(bytes) => Hash::from_slice(&Keccak256::digest(bytes)),
+        Token::FixedArray(_) | Token::Array(_) | Token::Tuple(_) => {
+            let mut encoded = Vec::new();
+            encode_in_place(token, &mut encoded);
+            Hash::from_slice(&Keccak256::digest(&encoded))
+        }
+        _ => Hash::from_slice(&encode(core::slice::from_ref(token))),
+    }
+}
+
+/// Concatenates the encodings of the members of arrays and tuples, padding strings and bytes
+/// to a multiple of 32 bytes.
+fn encode_in_place(token: &Token, out: &mut Vec<u8>) {
+    match token {
+        Token::String(string) => pad(string.as_bytes(), out),
+        Token::Bytes(bytes) => pad(bytes, out),
+        Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => {
+            for token in tokens {
+                encode_in_place(token, out);
+            }
+        }
+        _ => out.extend(encode(core::slice::from_ref(token))),
+    }
+}
+
+fn pad(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(bytes);
+    out.resize(out.len() + (32 - bytes.len() % 32) % 32, 0);
 }
 
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
+    use sha3::{Digest, Keccak256};
 
     #[cfg(not(feature = "std"))]
     use crate::no_std_prelude::*;
@@ -213,7 +295,7 @@ mod tests {
         log::{Log, RawLog},
         signature::long_signature,
         token::Token,
-        Event, EventParam, LogParam, ParamType, Uint,
+        Event, EventParam, Hash, LogParam, ParamType, TopicKind, Uint,
     };
 
     #[test]
@@ -221,21 +303,13 @@ mod tests {
         let event = Event {
             name: "foo".to_owned(),
             inputs: vec![
-                EventParam { name: "a".to_owned(), kind: ParamType::Int(256), indexed: false },
-                EventParam { name: "b".to_owned(), kind: ParamType::Int(256), indexed: true },
-                EventParam { name: "c".to_owned(), kind: ParamType::Address, indexed: false },
-                EventParam { name: "d".to_owned(), kind: ParamType::Address, indexed: true },
-                EventParam { name: "e".to_owned(), kind: ParamType::String, indexed: true },
-                EventParam {
-                    name: "f".to_owned(),
-                    kind: ParamType::Array(Box::new(ParamType::Int(256))),
-                    indexed: true,
-                },
-                EventParam {
-                    name: "g".to_owned(),
-                    kind: ParamType::FixedArray(Box::new(ParamType::Address), 5),
-                    indexed: true,
-                },
+                EventParam::new("a", ParamType::Int(256), false),
+                EventParam::new("b", ParamType::Int(256), true),
+                EventParam::new("c", ParamType::Address, false),
+                EventParam::new("d", ParamType::Address, true),
+                EventParam::new("e", ParamType::String, true),
+                EventParam::new("f", ParamType::Array(Box::new(ParamType::Int(256))), true),
+                EventParam::new("g", ParamType::FixedArray(Box::new(ParamType::Address), 5), true),
             ],
             anonymous: false,
         };
@@ -318,7 +392,16 @@ mod tests {
                 ]
                 .iter()
                 .cloned()
-                .map(|(name, value)| LogParam { name: name.to_string(), value })
+                .map(|(name, value)| LogParam {
+                    name: name.to_string(),
+                    value,
+                    hashed: match name {
+                        "e" => Some(ParamType::String),
+                        "f" => Some(ParamType::Array(Box::new(ParamType::Int(256)))),
+                        "g" => Some(ParamType::FixedArray(Box::new(ParamType::Address), 5)),
+                        _ => None,
+                    },
+                })
                 .collect::<Vec<_>>()
             }
         );
@@ -329,12 +412,12 @@ mod tests {
         let correct_event = Event {
             name: "Test".into(),
             inputs: vec![
-                EventParam {
-                    name: "tuple".into(),
-                    kind: ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
-                    indexed: false,
-                },
-                EventParam { name: "addr".into(), kind: ParamType::Address, indexed: true },
+                EventParam::new(
+                    "tuple",
+                    ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
+                    false,
+                ),
+                EventParam::new("addr", ParamType::Address, true),
             ],
             anonymous: false,
         };
@@ -361,4 +444,105 @@ mod tests {
         assert!(wrong_event.parse_log_validate(log.clone()).is_err());
         assert!(correct_event.parse_log_validate(log).is_ok());
     }
+
+    #[test]
+    fn test_encode_log() {
+        let mut event = Event {
+            name: "Noted".to_owned(),
+            inputs: vec![
+                EventParam::new("from", ParamType::Address, true),
+                EventParam::new("memo", ParamType::String, true),
+                EventParam::new("value", ParamType::Uint(256), false),
+                EventParam::new("ids", ParamType::Array(Box::new(ParamType::Uint(256))), true),
+            ],
+            anonymous: false,
+        };
+        let from = Token::Address(hex!("1111111111111111111111111111111111111111").into());
+        let value = Token::Uint(Uint::from(3));
+        let ids = Token::Array(vec![Token::Uint(Uint::from(1)), Token::Uint(Uint::from(2))]);
+        let tokens = [from.clone(), Token::String("hello".to_owned()), value.clone(), ids];
+
+        let log = event.encode_log(&tokens).unwrap();
+        let ids_hash = Keccak256::digest(hex!(
+            "
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000002
+		"
+        ));
+        assert_eq!(
+            log.topics,
+            [
+                event.signature(),
+                hex!("0000000000000000000000001111111111111111111111111111111111111111").into(),
+                hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").into(),
+                Hash::from_slice(&ids_hash),
+            ]
+        );
+        assert_eq!(
+            log.data,
+            hex!("0000000000000000000000000000000000000000000000000000000000000003")
+        );
+
+        let parsed = event.parse_log_validate(log).unwrap();
+        assert_eq!(parsed.params[0].value, from);
+        assert_eq!(
+            parsed.params[1].value,
+            Token::FixedBytes(
+                hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").to_vec()
+            )
+        );
+        assert_eq!(parsed.params[2].value, value);
+        assert_eq!(parsed.params[3].value, Token::FixedBytes(ids_hash.to_vec()));
+        assert_eq!(parsed.params[0].hashed, None);
+        assert_eq!(parsed.params[1].hashed, Some(ParamType::String));
+        assert!(parsed.params[0].matches(&from));
+        assert!(parsed.params[1].matches(&Token::String("hello".to_owned())));
+        assert!(!parsed.params[1].matches(&Token::String("world".to_owned())));
+        assert!(!parsed.params[1].matches(&Token::Bytes(b"hello".to_vec())));
+        assert!(parsed.params[3].matches(&tokens[3]));
+
+        event.anonymous = true;
+        let log = event.encode_log(&tokens).unwrap();
+        assert_eq!(log.topics.len(), 3);
+        assert!(event.parse_log_validate(log).is_ok());
+
+        assert!(event.encode_log(&tokens[..3]).is_err());
+        assert!(event.encode_log(&[from.clone(), from.clone(), value, from]).is_err());
+    }
+
+    #[test]
+    fn test_topic_layout() {
+        let mut event = Event {
+            name: "Noted".to_owned(),
+            inputs: vec![
+                EventParam::new("from", ParamType::Address, true),
+                EventParam::new("memo", ParamType::String, true),
+                EventParam::new("value", ParamType::Uint(256), false),
+            ],
+            anonymous: false,
+        };
+        let signature = event.signature();
+        let topic = Hash::from([0x11; 32]);
+
+        assert_eq!(event.topic0(), Some(signature));
+        assert_eq!(event.topic_count(), 3);
+        assert_eq!(
+            event.topic_kinds(),
+            [
+                TopicKind::Signature,
+                TopicKind::Value(ParamType::Address),
+                TopicKind::Hash(ParamType::String)
+            ]
+        );
+        assert!(event.matches_topics(&[signature, topic, topic]));
+        assert!(!event.matches_topics(&[topic, topic, topic]));
+        assert!(!event.matches_topics(&[signature, topic]));
+
+        event.anonymous = true;
+        assert_eq!(event.topic0(), None);
+        assert_eq!(event.topic_count(), 2);
+        assert_eq!(event.topic_kinds()[0], TopicKind::Value(ParamType::Address));
+        assert!(event.matches_topics(&[topic, topic]));
+        assert!(!event.matches_topics(&[signature, topic, topic]));
+    }
 }
@@ -20,9 +20,9 @@ use serde::{
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
 #[cfg(feature = "serde")]
-use crate::{param_type::Writer, TupleParam};
+use crate::param_type::Writer;
+use crate::{InternalType, ParamType, TupleParam};
 
 /// Event param specification.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,31 @@ pub struct EventParam {
     pub kind: ParamType,
     /// Indexed flag. If true, param is used to build block bloom.
     pub indexed: bool,
+    /// Solidity type the param was declared with, e.g. `enum Market.Side`, if known.
+    pub internal_type: Option<String>,
+    /// Named components of the tuple, if the param is a tuple or an array of tuples.
+    ///
+    /// Empty if only the types of the components are known from `kind`. Ignored unless they
+    /// describe the tuple of `kind`, see [`EventParam::named_components`].
+    pub components: Vec<TupleParam>,
+}
+
+impl EventParam {
+    /// Creates a param without an `internalType` or named components.
+    pub fn new(name: impl Into<String>, kind: ParamType, indexed: bool) -> Self {
+        EventParam { name: name.into(), kind, indexed, internal_type: None, components: vec![] }
+    }
+
+    /// Returns the named components of the tuple of the param, or none if `components` does
+    /// not describe the tuple of `kind`.
+    pub fn named_components(&self) -> &[TupleParam] {
+        crate::param::named_components(&self.kind, &self.components)
+    }
+
+    /// Parses the Solidity type the param was declared with, if `internal_type` is known.
+    pub fn parse_internal_type(&self) -> Option<InternalType> {
+        self.internal_type.as_deref().map(InternalType::parse)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -63,6 +88,7 @@ impl<'a> Visitor<'a> for EventParamVisitor {
         let mut name = None;
         let mut kind = None;
         let mut indexed = None;
+        let mut internal_type = None;
         let mut components = None;
 
         while let Some(ref key) = map.next_key::<String>()? {
@@ -92,14 +118,20 @@ impl<'a> Visitor<'a> for EventParamVisitor {
                     }
                     indexed = Some(map.next_value()?);
                 }
+                "internalType" => {
+                    if internal_type.is_some() {
+                        return Err(Error::duplicate_field("internalType"));
+                    }
+                    internal_type = Some(map.next_value()?);
+                }
                 _ => {}
             }
         }
         let name = name.ok_or_else(|| Error::missing_field("name"))?;
         let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-        crate::param::set_tuple_components(&mut kind, components)?;
+        let components = crate::param::set_tuple_components(&mut kind, components)?;
         let indexed = indexed.unwrap_or(false);
-        Ok(EventParam { name, kind, indexed })
+        Ok(EventParam { name, kind, indexed, internal_type, components })
     }
 }
 
@@ -113,10 +145,10 @@ impl Serialize for EventParam {
         map.serialize_entry("name", &self.name)?;
         map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
         map.serialize_entry("indexed", &self.indexed)?;
-        if let Some(inner_tuple) = crate::param::inner_tuple(&self.kind) {
-            map.serialize_key("components")?;
-            map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple))?;
+        if let Some(ref internal_type) = self.internal_type {
+            map.serialize_entry("internalType", internal_type)?;
         }
+        crate::param::serialize_components(&mut map, &self.kind, &self.components)?;
         map.end()
     }
 }
@@ -125,7 +157,10 @@ impl Serialize for EventParam {
 mod tests {
     #[cfg(not(feature = "std"))]
     use crate::no_std_prelude::*;
-    use crate::{tests::assert_json_eq, EventParam, ParamType};
+    use crate::{
+        tests::{assert_json_eq, component},
+        EventParam, InternalType, ParamType,
+    };
 
     #[test]
     fn event_param_deserialization() {
@@ -137,9 +172,26 @@ mod tests {
 
         let deserialized: EventParam = serde_json::from_str(s).unwrap();
 
+        assert_eq!(deserialized, EventParam::new("foo", ParamType::Address, true));
+
+        assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
+    }
+
+    #[test]
+    fn event_param_internal_type() {
+        let s = r#"{
+			"name": "side",
+			"type": "uint8",
+			"indexed": true,
+			"internalType": "enum Market.Side"
+		}"#;
+
+        let deserialized: EventParam = serde_json::from_str(s).unwrap();
+
+        assert_eq!(deserialized.internal_type.as_deref(), Some("enum Market.Side"));
         assert_eq!(
-            deserialized,
-            EventParam { name: "foo".to_owned(), kind: ParamType::Address, indexed: true }
+            deserialized.parse_internal_type(),
+            Some(InternalType::Enum { contract: Some("Market".to_owned()), ty: "Side".to_owned() })
         );
 
         assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
@@ -177,6 +229,15 @@ mod tests {
                     ParamType::Tuple(vec![ParamType::Address])
                 ]),
                 indexed: true,
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(
+                        None,
+                        ParamType::Tuple(vec![ParamType::Address]),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
@@ -249,6 +310,60 @@ mod tests {
                     ParamType::Uint(256),
                 ]),
                 indexed: false,
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(256), vec![]),
+                    component(None, ParamType::Address, vec![]),
+                    component(
+                        None,
+                        ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
+                        vec![
+                            component(None, ParamType::Address, vec![]),
+                            component(None, ParamType::Address, vec![]),
+                        ]
+                    ),
+                    component(None, ParamType::Uint(256), vec![]),
+                    component(
+                        None,
+                        ParamType::Array(Box::new(ParamType::Tuple(vec![
+                            ParamType::Array(Box::new(ParamType::Tuple(vec![
+                                ParamType::Address,
+                                ParamType::Bytes,
+                            ]))),
+                            ParamType::Array(Box::new(ParamType::Tuple(vec![
+                                ParamType::Address,
+                                ParamType::Uint(256)
+                            ]))),
+                            ParamType::Uint(256),
+                        ]))),
+                        vec![
+                            component(
+                                None,
+                                ParamType::Array(Box::new(ParamType::Tuple(vec![
+                                    ParamType::Address,
+                                    ParamType::Bytes,
+                                ]))),
+                                vec![
+                                    component(None, ParamType::Address, vec![]),
+                                    component(None, ParamType::Bytes, vec![]),
+                                ]
+                            ),
+                            component(
+                                None,
+                                ParamType::Array(Box::new(ParamType::Tuple(vec![
+                                    ParamType::Address,
+                                    ParamType::Uint(256)
+                                ]))),
+                                vec![
+                                    component(None, ParamType::Address, vec![]),
+                                    component(None, ParamType::Uint(256), vec![]),
+                                ]
+                            ),
+                            component(None, ParamType::Uint(256), vec![]),
+                        ]
+                    ),
+                    component(None, ParamType::Uint(256), vec![]),
+                ],
             }
         );
 
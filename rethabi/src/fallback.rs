@@ -0,0 +1,97 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fallback and receive functions.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Contract, Function, StateMutability};
+
+/// Contract fallback function, called with calldata no function matches.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Fallback {
+    /// Whether the fallback function accepts Ether, `nonpayable` unless given.
+    #[cfg_attr(feature = "serde", serde(rename = "stateMutability", default))]
+    pub state_mutability: StateMutability,
+}
+
+/// Contract receive function, called with empty calldata.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Receive {
+    /// Always `payable` in valid contracts.
+    #[cfg_attr(feature = "serde", serde(rename = "stateMutability"))]
+    pub state_mutability: StateMutability,
+}
+
+impl Default for Receive {
+    fn default() -> Self {
+        Receive { state_mutability: StateMutability::Payable }
+    }
+}
+
+/// What handles a call to a contract.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dispatch<'a> {
+    /// The function with the selector of the calldata.
+    Function(&'a Function),
+    /// The receive function, for empty calldata.
+    Receive(&'a Receive),
+    /// The fallback function, for calldata no function matches, or empty calldata if there
+    /// is no receive function.
+    Fallback(&'a Fallback),
+}
+
+impl Contract {
+    /// Returns what handles a call with the calldata, as the dispatcher generated by Solidity
+    /// does, or `None` if the call reverts.
+    pub fn dispatch(&self, calldata: &[u8]) -> Option<Dispatch<'_>> {
+        if calldata.is_empty() {
+            if let Some(receive) = &self.receive {
+                return Some(Dispatch::Receive(receive));
+            }
+        } else if calldata.len() >= 4 {
+            let selector = [calldata[0], calldata[1], calldata[2], calldata[3]];
+            if let Ok(function) = self.function_by_selector(selector) {
+                return Some(Dispatch::Function(function));
+            }
+        }
+        self.fallback.as_ref().map(Dispatch::Fallback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use crate::{Contract, Dispatch, Fallback, Function, Receive, StateMutability};
+
+    #[test]
+    fn dispatch() {
+        let mut contract = Contract::default();
+        contract.add_function(Function::new("foo", vec![], vec![], StateMutability::NonPayable));
+        let foo = contract.function("foo").unwrap();
+        let selector = foo.short_signature();
+
+        assert_eq!(contract.dispatch(&selector), Some(Dispatch::Function(foo)));
+        assert_eq!(contract.dispatch(&hex!("deadbeef")), None);
+        assert_eq!(contract.dispatch(&[]), None);
+
+        let fallback = Fallback { state_mutability: StateMutability::Payable };
+        contract.fallback = Some(fallback);
+        assert_eq!(contract.dispatch(&hex!("deadbeef")), Some(Dispatch::Fallback(&fallback)));
+        assert_eq!(contract.dispatch(&selector[..3]), Some(Dispatch::Fallback(&fallback)));
+        assert_eq!(contract.dispatch(&[]), Some(Dispatch::Fallback(&fallback)));
+
+        contract.receive = Some(Receive::default());
+        assert_eq!(contract.dispatch(&[]), Some(Dispatch::Receive(&Receive::default())));
+    }
+}
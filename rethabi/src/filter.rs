@@ -9,7 +9,7 @@
 use core::ops;
 
 #[cfg(feature = "serde")]
-use serde::{Serialize, Serializer};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
@@ -27,7 +27,7 @@ pub struct RawTopicFilter {
 }
 
 /// Topic filter.
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct TopicFilter {
     /// Usually (for not-anonymous transactions) the first topic is event signature.
     pub topic0: Topic<Hash>,
@@ -49,8 +49,24 @@ impl Serialize for TopicFilter {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'a> Deserialize<'a> for TopicFilter {
+    fn deserialize<D>(deserializer: D) -> Result<TopicFilter, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let topics = Vec::<Topic<Hash>>::deserialize(deserializer)?;
+        if topics.len() > 4 {
+            return Err(D::Error::invalid_length(topics.len(), &"at most 4 topics"));
+        }
+        let mut topics = topics.into_iter();
+        let mut next = || topics.next().unwrap_or(Topic::Any);
+        Ok(TopicFilter { topic0: next(), topic1: next(), topic2: next(), topic3: next() })
+    }
+}
+
 /// Acceptable topic possibilities.
-#[derive(Default, Debug, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub enum Topic<T> {
     /// Match any.
     #[default]
@@ -128,6 +144,39 @@ impl Serialize for Topic<Hash> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'a> Deserialize<'a> for Topic<Hash> {
+    fn deserialize<D>(deserializer: D) -> Result<Topic<Hash>, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        Ok(match Option::<OneOrMany<Hash>>::deserialize(deserializer)? {
+            None => Topic::Any,
+            Some(OneOrMany::One(hash)) => Topic::This(hash),
+            Some(OneOrMany::Many(hashes)) => Topic::OneOf(hashes),
+        })
+    }
+}
+
+/// Single value or array of values, as accepted by fields of JSON-RPC filters.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+#[cfg(feature = "serde")]
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(value: OneOrMany<T>) -> Self {
+        match value {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        }
+    }
+}
+
 impl<T> ops::Index<usize> for Topic<T> {
     type Output = T;
 
@@ -181,6 +230,36 @@ mod tests {
         assert_eq!(expected, &topic_str);
     }
 
+    #[cfg(feature = "full-serde")]
+    #[test]
+    fn test_topic_filter_deserialization() {
+        let json = r#"[
+			"0x000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b",
+			null,
+			["0x000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b"]
+		]"#;
+
+        let topic: TopicFilter = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            topic,
+            TopicFilter {
+                topic0: Topic::This(hash(
+                    "000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b",
+                )),
+                topic1: Topic::Any,
+                topic2: Topic::OneOf(vec![hash(
+                    "000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b",
+                )]),
+                topic3: Topic::Any,
+            }
+        );
+        let serialized = serde_json::to_string(&topic).unwrap();
+        assert_eq!(serde_json::from_str::<TopicFilter>(&serialized).unwrap(), topic);
+
+        assert!(serde_json::from_str::<TopicFilter>("[null, null, null, null, null]").is_err());
+        assert!(serde_json::from_str::<TopicFilter>(r#"["0x01"]"#).is_err());
+    }
+
     #[test]
     fn test_topic_from() {
         assert_eq!(Topic::Any as Topic<u64>, None.into());
@@ -0,0 +1,325 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Human readable rendering of calldata, return data and logs.
+
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{Address, Contract, Error, Hash, RawLog, Result, Token, TupleParam};
+
+/// Indentation of each nesting level.
+const INDENT: &str = "  ";
+
+/// Renders calldata, return data and logs of calls to a set of contracts as indented trees of
+/// named values, e.g.
+///
+/// ```text
+/// transfer(
+///   to: 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,
+///   amount: 1000000000000000000
+/// )
+/// ```
+///
+/// Names of tuple components are only known for params read from a JSON ABI.
+#[derive(Debug, Clone, Default)]
+pub struct Formatter<'a> {
+    contracts: Vec<&'a Contract>,
+}
+
+impl<'a> From<&'a Contract> for Formatter<'a> {
+    fn from(contract: &'a Contract) -> Self {
+        Formatter { contracts: vec![contract] }
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Creates a formatter without contracts.
+    pub fn new() -> Self {
+        Formatter { contracts: vec![] }
+    }
+
+    /// Adds a contract whose functions and events can be rendered.
+    pub fn add_contract(&mut self, contract: &'a Contract) -> &mut Self {
+        self.contracts.push(contract);
+        self
+    }
+
+    /// Renders the function call of the calldata.
+    pub fn format_call(&self, calldata: &[u8]) -> Result<String> {
+        let function = self.function(calldata)?;
+        let tokens = function.decode_input(&calldata[4..])?;
+        let params = function.inputs.iter().map(|p| (p.name.as_str(), p.named_components()));
+
+        let mut out = function.name.clone();
+        write_params(&mut out, params.zip(&tokens), "(", ")", 0);
+        Ok(out)
+    }
+
+    /// Renders the data returned by the function call of the calldata.
+    pub fn format_output(&self, calldata: &[u8], output: &[u8]) -> Result<String> {
+        let function = self.function(calldata)?;
+        let tokens = function.decode_output(output)?;
+        let params = function.outputs.iter().map(|p| (p.name.as_str(), p.named_components()));
+
+        let mut out = format!("{} -> ", function.name);
+        write_params(&mut out, params.zip(&tokens), "(", ")", 0);
+        Ok(out)
+    }
+
+    /// Renders the event emitted with the log.
+    ///
+    /// Anonymous events can not be told apart, so only logs of non-anonymous events are
+    /// supported.
+    pub fn format_log(&self, log: &RawLog) -> Result<String> {
+        let signature = log.topics.first().ok_or(Error::InvalidData)?;
+        let event = self
+            .contracts
+            .iter()
+            .find_map(|contract| contract.event_by_signature(signature).ok())
+            .ok_or_else(|| Error::InvalidName(hex::encode(signature)))?;
+        let parsed = event.parse_log(log.clone())?;
+        let params = event.inputs.iter().map(|p| (p.name.as_str(), p.named_components()));
+        let tokens = parsed.params.iter().map(|param| &param.value);
+
+        let mut out = event.name.clone();
+        write_params(&mut out, params.zip(tokens), "(", ")", 0);
+        Ok(out)
+    }
+
+    fn function(&self, calldata: &[u8]) -> Result<&'a crate::Function> {
+        let selector = calldata.get(..4).ok_or(Error::InvalidData)?;
+        let selector = [selector[0], selector[1], selector[2], selector[3]];
+        self.contracts
+            .iter()
+            .find_map(|contract| contract.function_by_selector(selector).ok())
+            .ok_or_else(|| Error::InvalidName(hex::encode(selector)))
+    }
+}
+
+/// Writes named values between `open` and `close`, one per line.
+fn write_params<'p, I>(out: &mut String, params: I, open: &str, close: &str, indent: usize)
+where
+    I: IntoIterator<Item = ((&'p str, &'p [TupleParam]), &'p Token)>,
+{
+    out.push_str(open);
+    let mut empty = true;
+    for ((name, components), token) in params {
+        out.push_str(if empty { "\n" } else { ",\n" });
+        empty = false;
+        push_indent(out, indent + 1);
+        if !name.is_empty() {
+            out.push_str(name);
+            out.push_str(": ");
+        }
+        write_value(out, token, components, indent + 1);
+    }
+    if !empty {
+        out.push('\n');
+        push_indent(out, indent);
+    }
+    out.push_str(close);
+}
+
+fn write_value(out: &mut String, token: &Token, components: &[TupleParam], indent: usize) {
+    match token {
+        Token::Address(address) => out.push_str(&checksum(address)),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => {
+            out.push_str("0x");
+            out.push_str(&hex::encode(bytes));
+        }
+        Token::Int(value) if value.bit(255) => out.push_str(&format!("-{}", value.wrapping_neg())),
+        Token::Int(value) | Token::Uint(value) => out.push_str(&value.to_string()),
+        Token::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+        Token::String(value) => out.push_str(&format!("{value:?}")),
+        Token::Function(address, selector) => {
+            out.push_str(&format!("{}.0x{}", checksum(address), hex::encode(selector)))
+        }
+        Token::Fixed(..) | Token::Ufixed(..) => out.push_str(&token.to_string()),
+        // Elements of arrays of tuples share the components of the tuple.
+        Token::Array(tokens) | Token::FixedArray(tokens) => {
+            let params = tokens.iter().map(|token| (("", components), token));
+            write_params(out, params, "[", "]", indent)
+        }
+        Token::Tuple(tokens) => {
+            let names = components
+                .iter()
+                .map(|c| (c.name.as_deref().unwrap_or_default(), c.named_components()))
+                .chain(core::iter::repeat(("", &[][..])));
+            write_params(out, names.zip(tokens), "(", ")", indent)
+        }
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize) {
+    for _ in 0..indent {
+        out.push_str(INDENT);
+    }
+}
+
+/// Returns the address with mixed-case checksum, as defined in EIP-55.
+fn checksum(address: &Address) -> String {
+    let address = hex::encode(address);
+    let hash = Hash::from_slice(&Keccak256::digest(address.as_bytes()));
+    let checksummed = address
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect::<String>();
+    format!("0x{checksummed}")
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{checksum, Formatter};
+    use crate::{encode, Address, Contract, Hash, RawLog, Token, Uint};
+
+    const ABI: &str = r#"[
+		{
+			"type": "function",
+			"name": "transfer",
+			"inputs": [
+				{ "name": "to", "type": "address" },
+				{ "name": "amount", "type": "uint256" }
+			],
+			"outputs": [{ "name": "", "type": "bool" }]
+		},
+		{
+			"type": "function",
+			"name": "submit",
+			"inputs": [
+				{
+					"name": "order",
+					"type": "tuple",
+					"components": [
+						{ "name": "maker", "type": "address" },
+						{ "name": "delta", "type": "int256" },
+						{ "name": "tags", "type": "string[]" }
+					]
+				},
+				{ "name": "legs", "type": "tuple[]", "components": [{ "name": "id", "type": "uint8" }] }
+			],
+			"outputs": []
+		},
+		{
+			"type": "event",
+			"name": "Transfer",
+			"inputs": [
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "value", "type": "uint256", "indexed": false }
+			],
+			"anonymous": false
+		}
+	]"#;
+
+    fn contract() -> Contract {
+        serde_json::from_str(ABI).unwrap()
+    }
+
+    const OWNER: [u8; 20] = hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(&Address::from(OWNER)), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(
+            checksum(&Address::from(hex!("fb6916095ca1df60bb79ce92ce3ea74c37c5d359"))),
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+        );
+    }
+
+    #[test]
+    fn format_call() {
+        let contract = contract();
+        let formatter = Formatter::from(&contract);
+        let function = contract.function("transfer").unwrap();
+        let calldata = function
+            .encode_input(&[
+                Token::Address(OWNER.into()),
+                Token::Uint(Uint::from(1_000_000_000_000_000_000u64)),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            formatter.format_call(&calldata).unwrap(),
+            "transfer(\n  to: 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,\n  amount: 1000000000000000000\n)"
+        );
+        assert_eq!(
+            formatter.format_output(&calldata, &encode(&[Token::Bool(true)])).unwrap(),
+            "transfer -> (\n  true\n)"
+        );
+        assert!(formatter.format_call(&hex!("deadbeef")).is_err());
+        assert!(Formatter::new().format_call(&calldata).is_err());
+    }
+
+    #[test]
+    fn format_nested_call() {
+        let contract = contract();
+        let function = contract.function("submit").unwrap();
+        let calldata = function
+            .encode_input(&[
+                Token::Tuple(vec![
+                    Token::Address(OWNER.into()),
+                    Token::Int(Uint::from(5).wrapping_neg()),
+                    Token::Array(vec![Token::String("a\"b".into())]),
+                ]),
+                Token::Array(vec![
+                    Token::Tuple(vec![Token::Uint(Uint::from(1))]),
+                    Token::Tuple(vec![Token::Uint(Uint::from(2))]),
+                ]),
+            ])
+            .unwrap();
+
+        let expected = r#"submit(
+  order: (
+    maker: 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,
+    delta: -5,
+    tags: [
+      "a\"b"
+    ]
+  ),
+  legs: [
+    (
+      id: 1
+    ),
+    (
+      id: 2
+    )
+  ]
+)"#;
+        assert_eq!(Formatter::from(&contract).format_call(&calldata).unwrap(), expected);
+    }
+
+    #[test]
+    fn format_log() {
+        let contract = contract();
+        let event = contract.event("Transfer").unwrap();
+        let mut from = [0u8; 32];
+        from[12..].copy_from_slice(&OWNER);
+        let log = RawLog {
+            topics: vec![event.signature(), Hash::from(from)],
+            data: encode(&[Token::Uint(Uint::from(7))]),
+        };
+
+        let mut formatter = Formatter::new();
+        formatter.add_contract(&contract);
+        assert_eq!(
+            formatter.format_log(&log).unwrap(),
+            "Transfer(\n  from: 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,\n  value: 7\n)"
+        );
+    }
+}
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
 use crate::{
-    decode, encode, signature::short_signature, Bytes, Error, Param, ParamType, Result,
+    decode, encode, signature::short_signature, Bytes, Decoded, Error, Param, ParamType, Result,
     StateMutability, Token,
 };
 
@@ -43,6 +43,17 @@ pub struct Function {
 }
 
 impl Function {
+    /// Creates a function without the deprecated `constant` flag.
+    pub fn new(
+        name: impl Into<String>,
+        inputs: Vec<Param>,
+        outputs: Vec<Param>,
+        state_mutability: StateMutability,
+    ) -> Self {
+        #[allow(deprecated)]
+        Function { name: name.into(), inputs, outputs, constant: None, state_mutability }
+    }
+
     /// Returns all input params of given function.
     fn input_param_types(&self) -> Vec<ParamType> {
         self.inputs.iter().map(|p| p.kind.clone()).collect()
@@ -63,7 +74,7 @@ impl Function {
 
         let signed = short_signature(&self.name, &params).to_vec();
         let encoded = encode(tokens);
-        Ok(signed.into_iter().chain(encoded.into_iter()).collect())
+        Ok(signed.into_iter().chain(encoded).collect())
     }
 
     /// Return the 4 byte short signature of this function.
@@ -82,6 +93,16 @@ impl Function {
         decode(&self.input_param_types(), data)
     }
 
+    /// Parses the ABI function output to params named after the outputs.
+    pub fn decode_output_named(&self, data: &[u8]) -> Result<Decoded> {
+        Ok(Decoded::new(&self.outputs, self.decode_output(data)?))
+    }
+
+    /// Parses the ABI function input to params named after the inputs.
+    pub fn decode_input_named(&self, data: &[u8]) -> Result<Decoded> {
+        Ok(Decoded::new(&self.inputs, self.decode_input(data)?))
+    }
+
     /// Returns a signature that uniquely identifies this function.
     ///
     /// Examples:
@@ -114,10 +135,7 @@ mod tests {
         #[allow(deprecated)]
         let func = Function {
             name: "baz".to_owned(),
-            inputs: vec![
-                Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None },
-                Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None },
-            ],
+            inputs: vec![Param::new("a", ParamType::Uint(32)), Param::new("b", ParamType::Bool)],
             outputs: vec![],
             constant: None,
             state_mutability: StateMutability::Payable,
@@ -0,0 +1,224 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solidity types of params, as found in `internalType`.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// Solidity type a param was declared with.
+///
+/// The ABI only describes how a value is encoded, so `enum Side` is encoded as `uint8` and
+/// `type Price is uint128` as `uint128`. The declared type is kept in `internalType`.
+///
+/// The `ty` of each variant keeps any array suffix, e.g. `Order[]` for `struct Market.Order[]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InternalType {
+    /// Elementary type, e.g. `uint256`, `address payable` or `bytes32[]`.
+    Elementary(String),
+    /// Contract or interface, e.g. `contract IERC20`.
+    Contract(String),
+    /// Enum, e.g. `enum Market.Side`.
+    Enum {
+        /// Contract the enum is declared in, if any.
+        contract: Option<String>,
+        /// Enum name.
+        ty: String,
+    },
+    /// Struct, e.g. `struct Market.Order`.
+    Struct {
+        /// Contract the struct is declared in, if any.
+        contract: Option<String>,
+        /// Struct name.
+        ty: String,
+    },
+    /// User-defined value type, e.g. `Market.Price` declared as `type Price is uint128`.
+    UserDefined {
+        /// Contract the type is declared in, if any.
+        contract: Option<String>,
+        /// Type name.
+        ty: String,
+    },
+}
+
+impl InternalType {
+    /// Parses the `internalType` of a param.
+    pub fn parse(internal_type: &str) -> Self {
+        if let Some(name) = internal_type.strip_prefix("contract ") {
+            return InternalType::Contract(name.to_owned());
+        }
+        if let Some(name) = internal_type.strip_prefix("enum ") {
+            let (contract, ty) = split_contract(name);
+            return InternalType::Enum { contract, ty };
+        }
+        if let Some(name) = internal_type.strip_prefix("struct ") {
+            let (contract, ty) = split_contract(name);
+            return InternalType::Struct { contract, ty };
+        }
+        if is_elementary(strip_array_suffix(internal_type)) {
+            return InternalType::Elementary(internal_type.to_owned());
+        }
+        let (contract, ty) = split_contract(internal_type);
+        InternalType::UserDefined { contract, ty }
+    }
+
+    /// Returns the type name without the declaring contract or keyword.
+    pub fn ty(&self) -> &str {
+        match self {
+            InternalType::Elementary(ty) | InternalType::Contract(ty) => ty,
+            InternalType::Enum { ty, .. }
+            | InternalType::Struct { ty, .. }
+            | InternalType::UserDefined { ty, .. } => ty,
+        }
+    }
+
+    /// Returns whether the type is an array of the declared type.
+    pub fn is_array(&self) -> bool {
+        self.ty().ends_with(']')
+    }
+}
+
+impl fmt::Display for InternalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (keyword, contract, ty) = match self {
+            InternalType::Elementary(ty) => return write!(f, "{ty}"),
+            InternalType::Contract(ty) => return write!(f, "contract {ty}"),
+            InternalType::Enum { contract, ty } => ("enum ", contract, ty),
+            InternalType::Struct { contract, ty } => ("struct ", contract, ty),
+            InternalType::UserDefined { contract, ty } => ("", contract, ty),
+        };
+        match contract {
+            Some(contract) => write!(f, "{keyword}{contract}.{ty}"),
+            None => write!(f, "{keyword}{ty}"),
+        }
+    }
+}
+
+fn split_contract(name: &str) -> (Option<String>, String) {
+    // Only the part before any array suffix may contain the contract name.
+    let base = strip_array_suffix(name);
+    match base.rfind('.') {
+        Some(dot) => (Some(name[..dot].to_owned()), name[dot + 1..].to_owned()),
+        None => (None, name.to_owned()),
+    }
+}
+
+fn strip_array_suffix(name: &str) -> &str {
+    match name.find('[') {
+        Some(bracket) => &name[..bracket],
+        None => name,
+    }
+}
+
+fn is_elementary(name: &str) -> bool {
+    fn is_sized(suffix: &str) -> bool {
+        suffix.chars().all(|c| c.is_ascii_digit())
+    }
+
+    fn is_fixed(suffix: &str) -> bool {
+        suffix.is_empty()
+            || suffix.split_once('x').map_or(false, |(len, decimals)| {
+                !len.is_empty() && !decimals.is_empty() && is_sized(len) && is_sized(decimals)
+            })
+    }
+
+    match name {
+        "address" | "address payable" | "bool" | "string" | "bytes" | "function" => true,
+        name if name.starts_with("function ") => true,
+        name => {
+            if let Some(suffix) = name.strip_prefix("uint").or_else(|| name.strip_prefix("int")) {
+                is_sized(suffix)
+            } else if let Some(suffix) = name.strip_prefix("bytes") {
+                !suffix.is_empty() && is_sized(suffix)
+            } else if let Some(suffix) =
+                name.strip_prefix("ufixed").or_else(|| name.strip_prefix("fixed"))
+            {
+                is_fixed(suffix)
+            } else {
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::InternalType;
+
+    #[test]
+    fn test_parse_internal_type() {
+        assert_eq!(InternalType::parse("uint256"), InternalType::Elementary("uint256".to_owned()));
+        assert_eq!(
+            InternalType::parse("address payable[]"),
+            InternalType::Elementary("address payable[]".to_owned())
+        );
+        assert_eq!(
+            InternalType::parse("function (uint256) external returns (bool)"),
+            InternalType::Elementary("function (uint256) external returns (bool)".to_owned())
+        );
+        assert_eq!(
+            InternalType::parse("contract IERC20"),
+            InternalType::Contract("IERC20".to_owned())
+        );
+        assert_eq!(
+            InternalType::parse("enum Market.Side"),
+            InternalType::Enum { contract: Some("Market".to_owned()), ty: "Side".to_owned() }
+        );
+        assert_eq!(
+            InternalType::parse("enum Side"),
+            InternalType::Enum { contract: None, ty: "Side".to_owned() }
+        );
+        assert_eq!(
+            InternalType::parse("struct Market.Order[2][]"),
+            InternalType::Struct {
+                contract: Some("Market".to_owned()),
+                ty: "Order[2][]".to_owned()
+            }
+        );
+        assert_eq!(
+            InternalType::parse("Market.Price"),
+            InternalType::UserDefined {
+                contract: Some("Market".to_owned()),
+                ty: "Price".to_owned()
+            }
+        );
+        assert_eq!(
+            InternalType::parse("Price"),
+            InternalType::UserDefined { contract: None, ty: "Price".to_owned() }
+        );
+        assert_eq!(
+            InternalType::parse("uint256x"),
+            InternalType::UserDefined { contract: None, ty: "uint256x".to_owned() }
+        );
+    }
+
+    #[test]
+    fn test_internal_type_display() {
+        for internal_type in [
+            "uint256",
+            "bytes32[]",
+            "contract IERC20",
+            "enum Market.Side",
+            "struct Order[]",
+            "Market.Price",
+        ] {
+            assert_eq!(InternalType::parse(internal_type).to_string(), internal_type);
+        }
+    }
+
+    #[test]
+    fn test_internal_type_is_array() {
+        assert!(InternalType::parse("struct Market.Order[]").is_array());
+        assert!(!InternalType::parse("enum Market.Side").is_array());
+        assert_eq!(InternalType::parse("struct Market.Order[]").ty(), "Order[]");
+    }
+}
@@ -19,7 +19,7 @@ mod no_std_prelude {
     pub use alloc::{
         borrow::{Cow, ToOwned},
         boxed::Box,
-        string::{self, String, ToString},
+        string::{String, ToString},
         vec::Vec,
     };
 }
@@ -30,27 +30,46 @@ mod no_std_prelude {
 #[cfg(not(feature = "std"))]
 use no_std_prelude::*;
 
+#[cfg(feature = "full-serde")]
+mod artifact;
+#[cfg(feature = "serde")]
+mod builder;
+mod bytecode;
+mod canonical;
+mod collision;
 mod constructor;
 mod contract;
+mod create;
+mod decoded;
 mod decoder;
+mod diff;
 mod encoder;
 mod error;
 mod errors;
 mod event;
 mod event_param;
+mod fallback;
 mod filter;
+mod formatter;
 mod function;
+mod internal_type;
 mod log;
+mod log_parser;
+mod logs_filter;
+mod merge;
+pub mod multicall;
 #[cfg(feature = "serde")]
 mod operation;
 mod param;
 pub mod param_type;
+mod revert;
 mod signature;
+mod solidity;
 mod state_mutability;
 pub mod token;
-#[cfg(feature = "serde")]
 mod tuple_param;
 mod util;
+mod validation;
 
 #[cfg(test)]
 mod tests;
@@ -58,25 +77,42 @@ mod tests;
 pub use revm_primitives as ethereum_types;
 pub use revm_primitives::{self, ruint};
 
+#[cfg(feature = "full-serde")]
+pub use crate::artifact::{Artifact, ArtifactFormat};
 #[cfg(feature = "serde")]
-pub use crate::tuple_param::TupleParam;
+pub use crate::builder::ContractBuilder;
 pub use crate::{
+    bytecode::{Bytecode, LinkReference},
+    canonical::CanonicalOptions,
+    collision::{selector_collisions, SelectorCollision},
     constructor::Constructor,
-    contract::{Contract, Events, Functions},
+    contract::{Contract, Events, Functions, Item},
+    create::{create2_address, create_address, init_code_hash, Deployment},
+    decoded::{Decoded, DecodedParam},
     decoder::{decode, decode_validate},
+    diff::{Change, ChangeKind},
     encoder::encode,
     error::Error as AbiError,
     errors::{Error, Result},
-    event::Event,
+    event::{Event, TopicKind},
     event_param::EventParam,
+    fallback::{Dispatch, Fallback, Receive},
     filter::{RawTopicFilter, Topic, TopicFilter},
+    formatter::Formatter,
     function::Function,
+    internal_type::InternalType,
     log::{Log, LogFilter, LogParam, ParseLog, RawLog},
+    log_parser::{LogLayout, LogParser, ParsedLog},
+    logs_filter::{BlockNumber, FilterBlocks, LogsFilter},
+    merge::MergeConflict,
     param::Param,
     param_type::ParamType,
-    signature::{long_signature, short_signature},
+    revert::Revert,
+    signature::{interface_id, long_signature, short_signature},
     state_mutability::StateMutability,
     token::Token,
+    tuple_param::TupleParam,
+    validation::{Diagnostic, Severity},
 };
 
 /// ABI word.
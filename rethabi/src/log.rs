@@ -7,11 +7,13 @@
 // except according to those terms.
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Bytes, Hash, Result, Token, TopicFilter};
+#[cfg(feature = "serde")]
+use crate::param_type::Writer;
+use crate::{event::encode_topic, Bytes, Hash, ParamType, Result, Token, TopicFilter};
 
 /// Common filtering functions that are available for any event.
 pub trait LogFilter {
@@ -53,6 +55,39 @@ pub struct LogParam {
     pub name: String,
     /// Decoded log value.
     pub value: Token,
+    /// Type of an indexed string, bytes, array or tuple param, whose topic is only the hash of
+    /// its value. `value` is then the hash, as `FixedBytes`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_hashed"
+        )
+    )]
+    pub hashed: Option<ParamType>,
+}
+
+impl LogParam {
+    /// Checks whether `candidate` is the value of the param, by comparing its hash if only
+    /// the hash of the value is known.
+    pub fn matches(&self, candidate: &Token) -> bool {
+        match (&self.hashed, &self.value) {
+            (Some(kind), Token::FixedBytes(hash)) => {
+                candidate.type_check(kind) && encode_topic(candidate).as_slice() == hash.as_slice()
+            }
+            (Some(_), _) => false,
+            (None, value) => value == candidate,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_hashed<S: Serializer>(
+    hashed: &Option<ParamType>,
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error> {
+    hashed.as_ref().map(Writer::write).serialize(serializer)
 }
 
 /// Decoded log.
@@ -62,3 +97,26 @@ pub struct Log {
     /// Log params.
     pub params: Vec<LogParam>,
 }
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use crate::{LogParam, ParamType, Token};
+
+    #[test]
+    fn log_param_serde() {
+        let param = LogParam {
+            name: "memo".to_owned(),
+            value: Token::FixedBytes(vec![0; 32]),
+            hashed: Some(ParamType::Array(Box::new(ParamType::String))),
+        };
+        let json = serde_json::to_string(&param).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["hashed"], "string[]");
+        assert_eq!(serde_json::from_str::<LogParam>(&json).unwrap(), param);
+
+        let param = LogParam { hashed: None, ..param };
+        let json = serde_json::to_string(&param).unwrap();
+        assert!(!json.contains("hashed"));
+        assert_eq!(serde_json::from_str::<LogParam>(&json).unwrap(), param);
+    }
+}
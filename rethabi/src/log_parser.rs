@@ -0,0 +1,158 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing logs of events emitted with different indexed params.
+
+use alloc::collections::BTreeMap;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{Contract, Error, Event, Hash, Log, RawLog, Result};
+
+/// Indexed params a log was parsed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLayout {
+    /// Layout of the event registered at this index among the events with the signature of
+    /// the log, the first one being the declared event.
+    Registered(usize),
+    /// Layout indexing the leading params of the declared event, as many as the log has topics
+    /// for, e.g. all params of an ERC-721 `Transfer` declared as an ERC-20 one.
+    Prefix,
+}
+
+/// Log parsed by a [`LogParser`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedLog {
+    /// Event with the indexed params the log was parsed with.
+    pub event: Event,
+    /// Which layout matched.
+    pub layout: LogLayout,
+    /// Decoded log.
+    pub log: Log,
+}
+
+/// Parses logs of events whose emitters disagree on which params are indexed.
+///
+/// Events are looked up by signature, which does not depend on the indexed params, and each
+/// event registered with that signature is tried in order, followed by the [`LogLayout::Prefix`]
+/// layout. Anonymous events are ignored, as their logs do not carry the signature.
+#[derive(Debug, Clone, Default)]
+pub struct LogParser {
+    events: BTreeMap<Hash, Vec<Event>>,
+}
+
+impl LogParser {
+    /// Creates a parser without events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an event, or a variant of a registered event with different indexed params.
+    pub fn register(&mut self, event: Event) {
+        if event.anonymous {
+            return;
+        }
+        let variants = self.events.entry(event.signature()).or_default();
+        if !variants.contains(&event) {
+            variants.push(event);
+        }
+    }
+
+    /// Parses a log with the first layout it decodes with.
+    pub fn parse_log(&self, log: RawLog) -> Result<ParsedLog> {
+        let signature = log.topics.first().ok_or(Error::InvalidData)?;
+        let events =
+            self.events.get(signature).ok_or_else(|| Error::InvalidName(hex::encode(signature)))?;
+
+        for (i, event) in events.iter().enumerate() {
+            if let Ok(parsed) = event.parse_log_validate(log.clone()) {
+                let event = event.clone();
+                return Ok(ParsedLog { event, layout: LogLayout::Registered(i), log: parsed });
+            }
+        }
+
+        let mut event = events[0].clone();
+        let mut indexed = log.topics.len() - 1;
+        for param in &mut event.inputs {
+            param.indexed = indexed > 0;
+            indexed = indexed.saturating_sub(1);
+        }
+        if indexed > 0 || events.contains(&event) {
+            return Err(Error::InvalidData);
+        }
+        let parsed = event.parse_log_validate(log)?;
+        Ok(ParsedLog { event, layout: LogLayout::Prefix, log: parsed })
+    }
+}
+
+impl From<&Contract> for LogParser {
+    fn from(contract: &Contract) -> Self {
+        let mut parser = LogParser::new();
+        for event in contract.events() {
+            parser.register(event.clone());
+        }
+        parser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{
+        Address, Error, Event, EventParam, LogLayout, LogParser, ParamType, RawLog, Token, Uint,
+    };
+
+    fn transfer(indexed: [bool; 3]) -> Event {
+        Event {
+            name: "Transfer".to_owned(),
+            inputs: vec![
+                EventParam::new("from", ParamType::Address, indexed[0]),
+                EventParam::new("to", ParamType::Address, indexed[1]),
+                EventParam::new("value", ParamType::Uint(256), indexed[2]),
+            ],
+            anonymous: false,
+        }
+    }
+
+    #[test]
+    fn parse_log_variants() {
+        let erc20 = transfer([true, true, false]);
+        let erc721 = transfer([true, true, true]);
+        let tokens = [
+            Token::Address(Address::from([0x11; 20])),
+            Token::Address(Address::from([0x22; 20])),
+            Token::Uint(Uint::from(7)),
+        ];
+        let erc20_log = erc20.encode_log(&tokens).unwrap();
+        let erc721_log = erc721.encode_log(&tokens).unwrap();
+
+        let mut parser = LogParser::new();
+        parser.register(erc20.clone());
+        assert!(erc20.parse_log(erc721_log.clone()).is_err());
+
+        let parsed = parser.parse_log(erc20_log).unwrap();
+        assert_eq!(parsed.layout, LogLayout::Registered(0));
+        assert_eq!(parsed.event, erc20);
+
+        let parsed = parser.parse_log(erc721_log.clone()).unwrap();
+        assert_eq!(parsed.layout, LogLayout::Prefix);
+        assert_eq!(parsed.event, erc721);
+        let values = parsed.log.params.into_iter().map(|param| param.value).collect::<Vec<_>>();
+        assert_eq!(values, tokens);
+
+        parser.register(erc721.clone());
+        assert_eq!(parser.parse_log(erc721_log).unwrap().layout, LogLayout::Registered(1));
+
+        let unindexed = transfer([false, false, false]).encode_log(&tokens).unwrap();
+        let log = RawLog { topics: vec![unindexed.topics[0]; 5], data: vec![] };
+        assert!(matches!(parser.parse_log(log), Err(Error::InvalidData)));
+        let log = RawLog { topics: vec![[0; 32].into()], data: unindexed.data };
+        assert!(matches!(parser.parse_log(log), Err(Error::InvalidName(_))));
+    }
+}
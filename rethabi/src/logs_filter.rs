@@ -0,0 +1,283 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Filter objects of `eth_getLogs`.
+
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::filter::OneOrMany;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{Address, Error, Event, Hash, RawTopicFilter, Result, TopicFilter};
+
+/// Block bounding the range of a [`LogsFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockNumber {
+    /// Block with this number.
+    Number(u64),
+    /// The genesis block.
+    Earliest,
+    /// The latest block.
+    Latest,
+    /// The latest block considered safe.
+    Safe,
+    /// The latest finalized block.
+    Finalized,
+    /// The pending block.
+    Pending,
+}
+
+impl fmt::Display for BlockNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlockNumber::Number(number) => write!(f, "0x{number:x}"),
+            BlockNumber::Earliest => write!(f, "earliest"),
+            BlockNumber::Latest => write!(f, "latest"),
+            BlockNumber::Safe => write!(f, "safe"),
+            BlockNumber::Finalized => write!(f, "finalized"),
+            BlockNumber::Pending => write!(f, "pending"),
+        }
+    }
+}
+
+impl FromStr for BlockNumber {
+    type Err = Error;
+
+    /// Parses a block tag or a hex quantity, e.g. `latest` or `0x1b4`.
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "earliest" => BlockNumber::Earliest,
+            "latest" => BlockNumber::Latest,
+            "safe" => BlockNumber::Safe,
+            "finalized" => BlockNumber::Finalized,
+            "pending" => BlockNumber::Pending,
+            _ => {
+                let digits = s.strip_prefix("0x").ok_or(Error::InvalidData)?;
+                let number = u64::from_str_radix(digits, 16).map_err(|_| Error::InvalidData)?;
+                BlockNumber::Number(number)
+            }
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for BlockNumber {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> Deserialize<'a> for BlockNumber {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<BlockNumber, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(|_| D::Error::custom(format!("invalid block number `{s}`")))
+    }
+}
+
+/// Blocks searched by a [`LogsFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterBlocks {
+    /// Blocks in a range, from and to the latest block unless given.
+    Range {
+        /// First block of the range.
+        from: Option<BlockNumber>,
+        /// Last block of the range.
+        to: Option<BlockNumber>,
+    },
+    /// The block with this hash.
+    Hash(Hash),
+}
+
+impl Default for FilterBlocks {
+    fn default() -> Self {
+        FilterBlocks::Range { from: None, to: None }
+    }
+}
+
+/// Filter object of `eth_getLogs`.
+///
+/// In JSON, the address may be a single address or an array, and topics may be `null` to match
+/// any value or an array to match one of several.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "LogsFilterObject", into = "LogsFilterObject"))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogsFilter {
+    /// Addresses of the contracts emitting the logs, any contract if empty.
+    pub address: Vec<Address>,
+    /// Blocks searched for logs.
+    pub blocks: FilterBlocks,
+    /// Topics of the logs.
+    pub topics: TopicFilter,
+}
+
+impl LogsFilter {
+    /// Creates a filter of the logs of `event` emitted by the contracts at `address`, with
+    /// indexed params matching `raw`.
+    pub fn from_event(event: &Event, address: Vec<Address>, raw: RawTopicFilter) -> Result<Self> {
+        Ok(LogsFilter { address, blocks: FilterBlocks::default(), topics: event.filter(raw)? })
+    }
+}
+
+/// JSON representation of a [`LogsFilter`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogsFilterObject {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    from_block: Option<BlockNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    to_block: Option<BlockNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    block_hash: Option<Hash>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", deserialize_with = "one_or_many")]
+    address: Vec<Address>,
+    #[serde(default)]
+    topics: TopicFilter,
+}
+
+#[cfg(feature = "serde")]
+fn one_or_many<'a, D>(deserializer: D) -> core::result::Result<Vec<Address>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    Ok(Option::<OneOrMany<Address>>::deserialize(deserializer)?.map(Vec::from).unwrap_or_default())
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LogsFilterObject> for LogsFilter {
+    type Error = &'static str;
+
+    fn try_from(object: LogsFilterObject) -> core::result::Result<Self, Self::Error> {
+        let blocks = match (object.block_hash, object.from_block, object.to_block) {
+            (Some(hash), None, None) => FilterBlocks::Hash(hash),
+            (Some(_), _, _) => return Err("`blockHash` can not be combined with a block range"),
+            (None, from, to) => FilterBlocks::Range { from, to },
+        };
+        Ok(LogsFilter { address: object.address, blocks, topics: object.topics })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<LogsFilter> for LogsFilterObject {
+    fn from(filter: LogsFilter) -> Self {
+        let (block_hash, from_block, to_block) = match filter.blocks {
+            FilterBlocks::Hash(hash) => (Some(hash), None, None),
+            FilterBlocks::Range { from, to } => (None, from, to),
+        };
+        LogsFilterObject {
+            from_block,
+            to_block,
+            block_hash,
+            address: filter.address,
+            topics: filter.topics,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use crate::{
+        tests::assert_json_eq, Address, BlockNumber, Event, EventParam, FilterBlocks, Hash,
+        LogsFilter, ParamType, RawTopicFilter, Token, Topic,
+    };
+
+    #[test]
+    fn block_number() {
+        assert_eq!("0x1b4".parse::<BlockNumber>().unwrap(), BlockNumber::Number(436));
+        assert_eq!("finalized".parse::<BlockNumber>().unwrap(), BlockNumber::Finalized);
+        assert!("436".parse::<BlockNumber>().is_err());
+        assert_eq!(BlockNumber::Number(436).to_string(), "0x1b4");
+    }
+
+    #[test]
+    fn logs_filter_serde() {
+        let json = r#"{
+			"fromBlock": "0x1b4",
+			"toBlock": "latest",
+			"address": "0x1111111111111111111111111111111111111111",
+			"topics": [
+				"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+				null,
+				[
+					"0x0000000000000000000000002222222222222222222222222222222222222222",
+					"0x0000000000000000000000003333333333333333333333333333333333333333"
+				]
+			]
+		}"#;
+
+        let filter: LogsFilter = serde_json::from_str(json).unwrap();
+        assert_eq!(filter.address, [Address::from([0x11; 20])]);
+        assert_eq!(
+            filter.blocks,
+            FilterBlocks::Range {
+                from: Some(BlockNumber::Number(436)),
+                to: Some(BlockNumber::Latest)
+            }
+        );
+        assert_eq!(filter.topics.topic1, Topic::Any);
+        assert!(matches!(&filter.topics.topic2, Topic::OneOf(hashes) if hashes.len() == 2));
+        assert_eq!(filter.topics.topic3, Topic::Any);
+
+        let serialized = serde_json::to_string(&filter).unwrap();
+        assert_eq!(serde_json::from_str::<LogsFilter>(&serialized).unwrap(), filter);
+
+        let filter = LogsFilter { blocks: FilterBlocks::Hash(Hash::from([0xaa; 32])), ..filter };
+        let serialized = serde_json::to_string(&filter).unwrap();
+        assert_eq!(serde_json::from_str::<LogsFilter>(&serialized).unwrap(), filter);
+
+        assert_eq!(serde_json::from_str::<LogsFilter>("{}").unwrap(), LogsFilter::default());
+        assert!(serde_json::from_str::<LogsFilter>(
+            r#"{
+			"fromBlock": "latest",
+			"blockHash": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+		}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn logs_filter_from_event() {
+        let event = Event {
+            name: "Transfer".to_owned(),
+            inputs: vec![
+                EventParam::new("from", ParamType::Address, true),
+                EventParam::new("value", ParamType::Uint(256), false),
+            ],
+            anonymous: false,
+        };
+        let raw = RawTopicFilter {
+            topic0: Topic::This(Token::Address(Address::from([0x22; 20]))),
+            ..RawTopicFilter::default()
+        };
+
+        let filter = LogsFilter::from_event(&event, vec![Address::from([0x11; 20])], raw).unwrap();
+        assert_json_eq(
+            &serde_json::to_string(&filter).unwrap(),
+            r#"{
+			"address": ["0x1111111111111111111111111111111111111111"],
+			"topics": [
+				"0x69ca02dd4edd7bf0a4abb9ed3b7af3f14778db5d61921c7dc7cd545266326de2",
+				"0x0000000000000000000000002222222222222222222222222222222222222222",
+				null,
+				null
+			]
+		}"#,
+        );
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Merging the ABIs of several contracts.
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    diff::{error_item, event_item, function_item},
+    signature::signature_string,
+    Contract, Event, Item, StateMutability,
+};
+
+/// Item of a merged contract which conflicts with an item already in the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// Item that was not merged, e.g. `function transfer(address,uint256)`.
+    pub item: String,
+    /// Why the item was not merged.
+    pub reason: String,
+}
+
+impl MergeConflict {
+    fn new(item: &str, reason: String) -> Self {
+        MergeConflict { item: item.to_owned(), reason }
+    }
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.item, self.reason)
+    }
+}
+
+impl Contract {
+    /// Adds the items of `other` to this contract, e.g. to compose the ABI of an implementation
+    /// with the ABIs of its modules.
+    ///
+    /// Items with the same signature as an item of this contract are merged if they are
+    /// identical apart from param names. Otherwise, and if a function or error shares the
+    /// selector of another one, the item of this contract is kept and the conflict reported.
+    pub fn merge(&mut self, other: &Contract) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+
+        if let Some(constructor) = &other.constructor {
+            match &self.constructor {
+                None => {
+                    self.constructor = Some(constructor.clone());
+                    self.items.push(Item::Constructor);
+                }
+                Some(existing)
+                    if signature_string("", &existing.inputs)
+                        == signature_string("", &constructor.inputs) => {}
+                Some(existing) => conflicts.push(MergeConflict::new(
+                    &format!("constructor{}", signature_string("", &constructor.inputs)),
+                    format!(
+                        "constructor{} already declared",
+                        signature_string("", &existing.inputs)
+                    ),
+                )),
+            }
+        }
+
+        // Items of this contract by selector or signature, the first declared one if several
+        // share it.
+        let mut functions = BTreeMap::new();
+        for function in self.functions() {
+            functions.entry(function.short_signature()).or_insert_with(|| function.clone());
+        }
+        let mut events = BTreeMap::new();
+        for event in self.events() {
+            events.entry(event.signature()).or_insert_with(|| event.clone());
+        }
+        let mut errors = BTreeMap::new();
+        for error in self.errors() {
+            let signature = error.signature();
+            errors
+                .entry([signature[0], signature[1], signature[2], signature[3]])
+                .or_insert_with(|| error.clone());
+        }
+
+        for function in other.functions() {
+            let item = function_item(function);
+            let selector = function.short_signature();
+            let Some(existing) = functions.get(&selector) else {
+                functions.insert(selector, function.clone());
+                self.add_function(function.clone());
+                continue;
+            };
+
+            let existing_item = function_item(existing);
+            let reason = if existing_item != item {
+                format!("selector 0x{} taken by {existing_item}", hex::encode(selector))
+            } else if signature_string("", &existing.outputs)
+                != signature_string("", &function.outputs)
+            {
+                format!(
+                    "outputs {} differ from {}",
+                    signature_string("", &function.outputs),
+                    signature_string("", &existing.outputs)
+                )
+            } else if existing.state_mutability != function.state_mutability {
+                mutability_reason(function.state_mutability, existing.state_mutability)
+            } else {
+                continue;
+            };
+            conflicts.push(MergeConflict::new(&item, reason));
+        }
+
+        for event in other.events() {
+            let item = event_item(event);
+            let signature = event.signature();
+            let Some(existing) = events.get(&signature) else {
+                events.insert(signature, event.clone());
+                self.add_event(event.clone());
+                continue;
+            };
+
+            let indexed = |e: &Event| e.inputs.iter().map(|p| p.indexed).collect::<Vec<_>>();
+            let reason = if existing.anonymous != event.anonymous {
+                "anonymous flag differs from the declared event".to_owned()
+            } else if indexed(existing) != indexed(event) {
+                "indexed params differ from the declared event".to_owned()
+            } else {
+                continue;
+            };
+            conflicts.push(MergeConflict::new(&item, reason));
+        }
+
+        for error in other.errors() {
+            let item = error_item(error);
+            let signature = error.signature();
+            let selector = [signature[0], signature[1], signature[2], signature[3]];
+            let Some(existing) = errors.get(&selector) else {
+                errors.insert(selector, error.clone());
+                self.add_error(error.clone());
+                continue;
+            };
+
+            if existing.signature() != signature {
+                let reason = format!(
+                    "selector 0x{} taken by {}",
+                    hex::encode(selector),
+                    error_item(existing)
+                );
+                conflicts.push(MergeConflict::new(&item, reason));
+            }
+        }
+
+        match (&self.receive, &other.receive) {
+            (None, Some(receive)) => {
+                self.receive = Some(*receive);
+                self.items.push(Item::Receive);
+            }
+            (Some(existing), Some(receive)) if existing != receive => {
+                conflicts.push(MergeConflict::new(
+                    "receive",
+                    mutability_reason(receive.state_mutability, existing.state_mutability),
+                ));
+            }
+            _ => {}
+        }
+        match (&self.fallback, &other.fallback) {
+            (None, Some(fallback)) => {
+                self.fallback = Some(*fallback);
+                self.items.push(Item::Fallback);
+            }
+            (Some(existing), Some(fallback)) if existing != fallback => {
+                conflicts.push(MergeConflict::new(
+                    "fallback",
+                    mutability_reason(fallback.state_mutability, existing.state_mutability),
+                ));
+            }
+            _ => {}
+        }
+
+        conflicts
+    }
+}
+
+fn mutability_reason(state_mutability: StateMutability, existing: StateMutability) -> String {
+    format!("state mutability {state_mutability} differs from {existing}")
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{Contract, Item, StateMutability};
+
+    #[test]
+    fn merge() {
+        let mut implementation = Contract::builder()
+            .constructor()
+            .input("owner", "address")
+            .function("owner")
+            .output("", "address")
+            .state_mutability(StateMutability::View)
+            .event("OwnershipTransferred")
+            .indexed("previousOwner", "address")
+            .indexed("newOwner", "address")
+            .error("Unauthorized")
+            .build()
+            .unwrap();
+        let facet = Contract::builder()
+            .constructor()
+            .input("owner", "address")
+            .function("owner")
+            .output("owner_", "address")
+            .state_mutability(StateMutability::View)
+            .function("transfer")
+            .input("to", "address")
+            .input("amount", "uint256")
+            .output("", "bool")
+            .event("OwnershipTransferred")
+            .indexed("from", "address")
+            .indexed("to", "address")
+            .error("Unauthorized")
+            .receive()
+            .build()
+            .unwrap();
+
+        assert!(implementation.merge(&facet).is_empty());
+        assert_eq!(implementation.functions().count(), 2);
+        assert_eq!(implementation.events().count(), 1);
+        assert_eq!(implementation.errors().count(), 1);
+        assert!(implementation.receive.is_some());
+        assert_eq!(
+            implementation.items[4..],
+            [Item::Function("transfer".to_owned(), 0), Item::Receive]
+        );
+
+        // Merging again changes nothing.
+        let merged = implementation.clone();
+        assert!(implementation.merge(&facet).is_empty());
+        assert_eq!(implementation, merged);
+    }
+
+    #[test]
+    fn merge_conflicts() {
+        let mut implementation = Contract::builder()
+            .constructor()
+            .input("owner", "address")
+            .function("owner")
+            .output("", "address")
+            .state_mutability(StateMutability::View)
+            .function("transfer")
+            .input("to", "address")
+            .input("amount", "uint256")
+            .output("", "bool")
+            .function("balanceOf")
+            .input("owner", "address")
+            .output("", "uint256")
+            .state_mutability(StateMutability::View)
+            .event("Transfer")
+            .indexed("from", "address")
+            .input("value", "uint256")
+            .fallback()
+            .build()
+            .unwrap();
+        let facet = Contract::builder()
+            .constructor()
+            .function("owner")
+            .output("", "address")
+            .function("many_msg_babbage")
+            .input("", "bytes1")
+            .function("balanceOf")
+            .input("owner", "address")
+            .output("", "uint128")
+            .state_mutability(StateMutability::View)
+            .event("Transfer")
+            .indexed("from", "address")
+            .indexed("value", "uint256")
+            .fallback()
+            .state_mutability(StateMutability::Payable)
+            .build()
+            .unwrap();
+
+        let merged = implementation.clone();
+        let conflicts =
+            implementation.merge(&facet).iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            conflicts,
+            [
+                "constructor(): constructor(address) already declared",
+                "function owner(): state mutability nonpayable differs from view",
+                "function many_msg_babbage(bytes1): selector 0xa9059cbb taken by function transfer(address,uint256)",
+                "function balanceOf(address): outputs (uint128) differ from (uint256)",
+                "event Transfer(address,uint256): indexed params differ from the declared event",
+                "fallback: state mutability payable differs from nonpayable",
+            ]
+        );
+        assert_eq!(implementation, merged);
+    }
+}
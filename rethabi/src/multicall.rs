@@ -0,0 +1,257 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Batching calls with [Multicall3](https://github.com/mds1/multicall).
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    decode, encode, short_signature, Address, Bytes, Error, Function, ParamType, Result, Revert,
+    Token,
+};
+
+/// Address Multicall3 is deployed at on most chains.
+pub const MULTICALL3_ADDRESS: Address = revm_primitives::B160([
+    0xca, 0x11, 0xbd, 0xe0, 0x59, 0x77, 0xb3, 0x63, 0x11, 0x67, 0x02, 0x88, 0x62, 0xbe, 0x2a, 0x17,
+    0x39, 0x76, 0xca, 0x11,
+]);
+
+/// Call to a function of a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call<'a> {
+    /// Contract to call.
+    pub target: Address,
+    /// Function to call.
+    pub function: &'a Function,
+    /// Function input.
+    pub tokens: Vec<Token>,
+    /// Whether the batch may go on if this call reverts. Only used by `aggregate3`.
+    pub allow_failure: bool,
+}
+
+/// Batch of calls, sent through Multicall3.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Multicall<'a> {
+    /// Calls, in the order they are made.
+    pub calls: Vec<Call<'a>>,
+}
+
+impl<'a> FromIterator<(Address, &'a Function, Vec<Token>)> for Multicall<'a> {
+    fn from_iter<I: IntoIterator<Item = (Address, &'a Function, Vec<Token>)>>(calls: I) -> Self {
+        let mut multicall = Multicall::new();
+        for (target, function, tokens) in calls {
+            multicall.add(target, function, tokens);
+        }
+        multicall
+    }
+}
+
+impl<'a> Multicall<'a> {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Multicall { calls: vec![] }
+    }
+
+    /// Adds a call which is allowed to fail.
+    pub fn add(
+        &mut self,
+        target: Address,
+        function: &'a Function,
+        tokens: Vec<Token>,
+    ) -> &mut Self {
+        self.calls.push(Call { target, function, tokens, allow_failure: true });
+        self
+    }
+
+    /// Encodes the calldata of `aggregate3((address,bool,bytes)[])`.
+    pub fn encode_aggregate3(&self) -> Result<Bytes> {
+        let calls = self
+            .calls
+            .iter()
+            .map(|call| {
+                Ok(Token::Tuple(vec![
+                    Token::Address(call.target),
+                    Token::Bool(call.allow_failure),
+                    Token::Bytes(call.function.encode_input(&call.tokens)?),
+                ]))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let kind = ParamType::Tuple(vec![ParamType::Address, ParamType::Bool, ParamType::Bytes]);
+        Ok(encode_call("aggregate3", &[ParamType::Array(Box::new(kind))], &[Token::Array(calls)]))
+    }
+
+    /// Encodes the calldata of `tryAggregate(bool,(address,bytes)[])`.
+    ///
+    /// If `require_success` is set, the whole batch reverts if any call does.
+    pub fn encode_try_aggregate(&self, require_success: bool) -> Result<Bytes> {
+        let calls = self
+            .calls
+            .iter()
+            .map(|call| {
+                Ok(Token::Tuple(vec![
+                    Token::Address(call.target),
+                    Token::Bytes(call.function.encode_input(&call.tokens)?),
+                ]))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let kind = ParamType::Tuple(vec![ParamType::Address, ParamType::Bytes]);
+        Ok(encode_call(
+            "tryAggregate",
+            &[ParamType::Bool, ParamType::Array(Box::new(kind))],
+            &[Token::Bool(require_success), Token::Array(calls)],
+        ))
+    }
+
+    /// Decodes the output of `aggregate3` or `tryAggregate`, which both return
+    /// `(bool success, bytes returnData)[]`, into the output of each call.
+    ///
+    /// A call which succeeds but returns data that is not a valid output of its function, e.g. a
+    /// call to an account without code, is reported as [`Revert::Raw`] with the returned data.
+    pub fn decode_output(
+        &self,
+        data: &[u8],
+    ) -> Result<Vec<core::result::Result<Vec<Token>, Revert>>> {
+        let kind = ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes]);
+        let results = decode(&[ParamType::Array(Box::new(kind))], data)?
+            .pop()
+            .and_then(Token::into_array)
+            .ok_or(Error::InvalidData)?;
+        if results.len() != self.calls.len() {
+            return Err(Error::InvalidData);
+        }
+
+        self.calls
+            .iter()
+            .zip(results)
+            .map(|(call, result)| {
+                let mut result = result.into_tuple().ok_or(Error::InvalidData)?.into_iter();
+                let (success, data) = match (result.next(), result.next()) {
+                    (Some(Token::Bool(success)), Some(Token::Bytes(data))) => (success, data),
+                    _ => return Err(Error::InvalidData),
+                };
+                Ok(match success {
+                    true => call.function.decode_output(&data).map_err(|_| Revert::Raw(data)),
+                    false => Err(Revert::decode(&data)),
+                })
+            })
+            .collect()
+    }
+}
+
+fn encode_call(name: &str, params: &[ParamType], tokens: &[Token]) -> Bytes {
+    let mut data = short_signature(name, params).to_vec();
+    data.extend(encode(tokens));
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use crate::{
+        encode,
+        multicall::{Multicall, MULTICALL3_ADDRESS},
+        Address, Function, Param, ParamType, Revert, StateMutability, Token, Uint,
+    };
+
+    fn balance_of() -> Function {
+        #[allow(deprecated)]
+        Function {
+            name: "balanceOf".into(),
+            inputs: vec![Param::new("owner", ParamType::Address)],
+            outputs: vec![Param::new("", ParamType::Uint(256))],
+            constant: None,
+            state_mutability: StateMutability::View,
+        }
+    }
+
+    #[test]
+    fn multicall3_address() {
+        assert_eq!(
+            MULTICALL3_ADDRESS,
+            Address::from(hex!("ca11bde05977b3631167028862be2a173976ca11"))
+        );
+    }
+
+    #[test]
+    fn encode_aggregate3() {
+        let function = balance_of();
+        let token = Address::from([0x11u8; 20]);
+        let owner = Address::from([0x22u8; 20]);
+        let multicall: Multicall =
+            vec![(token, &function, vec![Token::Address(owner)])].into_iter().collect();
+
+        let encoded = multicall.encode_aggregate3().unwrap();
+        let expected = hex!(
+            "
+			82ad56cb
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000001111111111111111111111111111111111111111
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000060
+			0000000000000000000000000000000000000000000000000000000000000024
+			70a0823100000000000000000000000022222222222222222222222222222222
+			2222222200000000000000000000000000000000000000000000000000000000
+		"
+        );
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_try_aggregate() {
+        let function = balance_of();
+        let mut multicall = Multicall::new();
+        multicall.add([0x11u8; 20].into(), &function, vec![Token::Address([0x22u8; 20].into())]);
+
+        let encoded = multicall.encode_try_aggregate(false).unwrap();
+        assert_eq!(encoded[..4], hex!("bce38bd7"));
+
+        multicall.add([0x11u8; 20].into(), &function, vec![]);
+        assert!(multicall.encode_aggregate3().is_err());
+    }
+
+    #[test]
+    fn decode_output() {
+        let function = balance_of();
+        let mut multicall = Multicall::new();
+        for _ in 0..3 {
+            multicall.add(
+                [0x11u8; 20].into(),
+                &function,
+                vec![Token::Address([0x22u8; 20].into())],
+            );
+        }
+
+        let revert = hex!(
+            "
+			4e487b71
+			0000000000000000000000000000000000000000000000000000000000000011
+		"
+        );
+        let output = encode(&[Token::Array(vec![
+            Token::Tuple(vec![
+                Token::Bool(true),
+                Token::Bytes(encode(&[Token::Uint(Uint::from(5))])),
+            ]),
+            Token::Tuple(vec![Token::Bool(false), Token::Bytes(revert.to_vec())]),
+            Token::Tuple(vec![Token::Bool(true), Token::Bytes(vec![])]),
+        ])]);
+
+        let results = multicall.decode_output(&output).unwrap();
+        assert_eq!(results[0], Ok(vec![Token::Uint(Uint::from(5))]));
+        assert_eq!(results[1], Err(Revert::Panic(Uint::from(0x11))));
+        assert_eq!(results[2], Err(Revert::Raw(vec![])));
+
+        multicall.calls.pop();
+        assert!(multicall.decode_output(&output).is_err());
+    }
+}
@@ -8,7 +8,7 @@
 
 //! Operation type.
 
-use crate::{error::Error, Constructor, Event, Function};
+use crate::{error::Error, Constructor, Event, Fallback, Function, Receive};
 use serde::{Deserialize, Serialize};
 
 /// Operation type.
@@ -29,10 +29,10 @@ pub enum Operation {
     Error(Error),
     /// Fallback function.
     #[serde(rename = "fallback")]
-    Fallback,
+    Fallback(Fallback),
     /// Receive function.
     #[serde(rename = "receive")]
-    Receive,
+    Receive(Receive),
 }
 
 #[cfg(test)]
@@ -42,6 +42,7 @@ mod tests {
     use crate::no_std_prelude::*;
     use crate::{
         tests::assert_ser_de, Event, EventParam, Function, Param, ParamType, StateMutability,
+        TupleParam,
     };
 
     #[test]
@@ -61,11 +62,7 @@ mod tests {
         #[allow(deprecated)]
         let function = Function {
             name: "foo".to_owned(),
-            inputs: vec![Param {
-                name: "a".to_owned(),
-                kind: ParamType::Address,
-                internal_type: None,
-            }],
+            inputs: vec![Param::new("a", ParamType::Address)],
             outputs: vec![],
             constant: None,
             state_mutability: StateMutability::NonPayable,
@@ -121,7 +118,7 @@ mod tests {
             Operation::Event(Event {
                 name: "E".to_owned(),
                 inputs: vec![
-                    EventParam { name: "a".to_owned(), kind: ParamType::Address, indexed: true },
+                    EventParam::new("a", ParamType::Address, true),
                     EventParam {
                         name: "b".to_owned(),
                         kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
@@ -129,7 +126,28 @@ mod tests {
                             ParamType::Uint(256),
                             ParamType::Bytes
                         ]))),
-                        indexed: false
+                        indexed: false,
+                        internal_type: Some("struct Action[]".to_owned()),
+                        components: vec![
+                            TupleParam {
+                                name: Some("to".to_owned()),
+                                kind: ParamType::Address,
+                                internal_type: Some("address".to_owned()),
+                                components: vec![],
+                            },
+                            TupleParam {
+                                name: Some("value".to_owned()),
+                                kind: ParamType::Uint(256),
+                                internal_type: Some("uint256".to_owned()),
+                                components: vec![],
+                            },
+                            TupleParam {
+                                name: Some("data".to_owned()),
+                                kind: ParamType::Bytes,
+                                internal_type: Some("bytes".to_owned()),
+                                components: vec![],
+                            },
+                        ],
                     },
                 ],
                 anonymous: false,
@@ -20,9 +20,9 @@ use serde::{
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
 #[cfg(feature = "serde")]
-use crate::{param_type::Writer, TupleParam};
+use crate::param_type::Writer;
+use crate::{InternalType, ParamType, TupleParam};
 
 /// Function param.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,29 @@ pub struct Param {
     pub kind: ParamType,
     /// Additional Internal type.
     pub internal_type: Option<String>,
+    /// Named components of the tuple, if the param is a tuple or an array of tuples.
+    ///
+    /// Empty if only the types of the components are known from `kind`. Ignored unless they
+    /// describe the tuple of `kind`, see [`Param::named_components`].
+    pub components: Vec<TupleParam>,
+}
+
+impl Param {
+    /// Creates a param without an `internalType` or named components.
+    pub fn new(name: impl Into<String>, kind: ParamType) -> Self {
+        Param { name: name.into(), kind, internal_type: None, components: vec![] }
+    }
+
+    /// Returns the named components of the tuple of the param, or none if `components` does
+    /// not describe the tuple of `kind`.
+    pub fn named_components(&self) -> &[TupleParam] {
+        named_components(&self.kind, &self.components)
+    }
+
+    /// Parses the Solidity type the param was declared with, if `internal_type` is known.
+    pub fn parse_internal_type(&self) -> Option<InternalType> {
+        self.internal_type.as_deref().map(InternalType::parse)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -97,8 +120,8 @@ impl<'a> Visitor<'a> for ParamVisitor {
         }
         let name = name.ok_or_else(|| Error::missing_field("name"))?;
         let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-        set_tuple_components::<V::Error>(&mut kind, components)?;
-        Ok(Param { name, kind, internal_type })
+        let components = set_tuple_components::<V::Error>(&mut kind, components)?;
+        Ok(Param { name, kind, internal_type, components })
     }
 }
 
@@ -114,12 +137,29 @@ impl Serialize for Param {
         }
         map.serialize_entry("name", &self.name)?;
         map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
-        if let Some(inner_tuple) = inner_tuple(&self.kind) {
-            map.serialize_key("components")?;
+        serialize_components(&mut map, &self.kind, &self.components)?;
+        map.end()
+    }
+}
+
+/// Serializes the components of a tuple param, falling back to unnamed components taken from
+/// `kind` if the names are not known or do not match `kind`.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_components<M: SerializeMap>(
+    map: &mut M,
+    kind: &ParamType,
+    components: &[TupleParam],
+) -> Result<(), M::Error> {
+    if let Some(inner_tuple) = inner_tuple(kind) {
+        map.serialize_key("components")?;
+        let components = named_components(kind, components);
+        if components.is_empty() {
             map.serialize_value(&SerializeableParamVec(inner_tuple))?;
+        } else {
+            map.serialize_value(components)?;
         }
-        map.end()
     }
+    Ok(())
 }
 
 #[cfg(feature = "serde")]
@@ -134,7 +174,6 @@ pub(crate) fn inner_tuple_mut(mut param: &mut ParamType) -> Option<&mut Vec<Para
     }
 }
 
-#[cfg(feature = "serde")]
 pub(crate) fn inner_tuple(mut param: &ParamType) -> Option<&Vec<ParamType>> {
     loop {
         match param {
@@ -146,16 +185,36 @@ pub(crate) fn inner_tuple(mut param: &ParamType) -> Option<&Vec<ParamType>> {
     }
 }
 
+/// Returns `components` if they describe the tuple of `kind`, i.e. have the types of its
+/// elements, or no components otherwise.
+pub(crate) fn named_components<'a>(
+    kind: &ParamType,
+    components: &'a [TupleParam],
+) -> &'a [TupleParam] {
+    match inner_tuple(kind) {
+        Some(kinds)
+            if kinds.len() == components.len()
+                && kinds.iter().zip(components).all(|(kind, c)| *kind == c.kind) =>
+        {
+            components
+        }
+        _ => &[],
+    }
+}
+
 #[cfg(feature = "serde")]
 pub(crate) fn set_tuple_components<Error: serde::de::Error>(
     kind: &mut ParamType,
     components: Option<Vec<TupleParam>>,
-) -> Result<(), Error> {
-    if let Some(inner_tuple_mut) = inner_tuple_mut(kind) {
-        let tuple_params = components.ok_or_else(|| Error::missing_field("components"))?;
-        inner_tuple_mut.extend(tuple_params.into_iter().map(|param| param.kind))
+) -> Result<Vec<TupleParam>, Error> {
+    match inner_tuple_mut(kind) {
+        Some(inner_tuple_mut) => {
+            let tuple_params = components.ok_or_else(|| Error::missing_field("components"))?;
+            inner_tuple_mut.extend(tuple_params.iter().map(|param| param.kind.clone()));
+            Ok(tuple_params)
+        }
+        None => Ok(vec![]),
     }
-    Ok(())
 }
 
 #[cfg(feature = "serde")]
@@ -199,8 +258,8 @@ mod tests {
     #[cfg(not(feature = "std"))]
     use crate::no_std_prelude::*;
     use crate::{
-        tests::{assert_json_eq, assert_ser_de},
-        Param, ParamType,
+        tests::{assert_json_eq, assert_ser_de, component},
+        InternalType, Param, ParamType,
     };
 
     #[test]
@@ -212,10 +271,7 @@ mod tests {
 
         let deserialized: Param = serde_json::from_str(s).unwrap();
 
-        assert_eq!(
-            deserialized,
-            Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None }
-        );
+        assert_eq!(deserialized, Param::new("foo", ParamType::Address));
 
         assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
     }
@@ -235,9 +291,17 @@ mod tests {
             Param {
                 name: "foo".to_owned(),
                 kind: ParamType::Address,
-                internal_type: Some("struct Verifier.Proof".to_string())
+                internal_type: Some("struct Verifier.Proof".to_string()),
+                components: vec![],
             }
         );
+        assert_eq!(
+            deserialized.parse_internal_type(),
+            Some(InternalType::Struct {
+                contract: Some("Verifier".to_owned()),
+                ty: "Proof".to_owned()
+            })
+        );
 
         assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
     }
@@ -272,7 +336,15 @@ mod tests {
                     ParamType::Uint(48),
                     ParamType::Tuple(vec![ParamType::Address])
                 ]),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(
+                        None,
+                        ParamType::Tuple(vec![ParamType::Address]),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
@@ -310,7 +382,15 @@ mod tests {
                     ParamType::Uint(48),
                     ParamType::Tuple(vec![ParamType::Address])
                 ]),
-                internal_type: Some("struct Pairing.G1Point[]".to_string())
+                internal_type: Some("struct Pairing.G1Point[]".to_string()),
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(
+                        None,
+                        ParamType::Tuple(vec![ParamType::Address]),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
@@ -350,13 +430,49 @@ mod tests {
                     ParamType::Uint(48),
                     ParamType::Tuple(vec![ParamType::Address])
                 ]),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(Some("amount"), ParamType::Uint(48), vec![]),
+                    component(
+                        Some("things"),
+                        ParamType::Tuple(vec![ParamType::Address]),
+                        vec![component(Some("baseTupleParam"), ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
         assert_ser_de(&deserialized);
     }
 
+    #[test]
+    fn param_tuple_stale_components() {
+        let mut param = Param {
+            name: "foo".to_owned(),
+            kind: ParamType::Tuple(vec![ParamType::Uint(48)]),
+            internal_type: None,
+            components: vec![component(Some("amount"), ParamType::Uint(48), vec![])],
+        };
+        assert_eq!(param.named_components(), &param.components[..]);
+
+        param.kind = ParamType::Array(Box::new(ParamType::Tuple(vec![
+            ParamType::Uint(48),
+            ParamType::Bool,
+        ])));
+        assert!(param.named_components().is_empty());
+        assert_json_eq(
+            &serde_json::to_string(&param).unwrap(),
+            r#"{
+			"name": "foo",
+			"type": "tuple[]",
+			"components": [{ "type": "uint48" }, { "type": "bool" }]
+		}"#,
+        );
+
+        param.kind = ParamType::Tuple(vec![ParamType::Bool]);
+        assert!(param.named_components().is_empty());
+    }
+
     #[test]
     fn param_tuple_array() {
         let s = r#"{
@@ -386,7 +502,12 @@ mod tests {
                     ParamType::Address,
                     ParamType::Address
                 ]))),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(None, ParamType::Address, vec![]),
+                    component(None, ParamType::Address, vec![]),
+                ],
             }
         );
 
@@ -416,7 +537,11 @@ mod tests {
                 kind: ParamType::Array(Box::new(ParamType::Array(Box::new(ParamType::Tuple(
                     vec![ParamType::Uint(8), ParamType::Uint(16),]
                 ))))),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(8), vec![]),
+                    component(None, ParamType::Uint(16), vec![]),
+                ],
             }
         );
 
@@ -455,7 +580,12 @@ mod tests {
                     ])),
                     2
                 ),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(None, ParamType::Address, vec![]),
+                    component(None, ParamType::Address, vec![]),
+                ],
             }
         );
 
@@ -497,7 +627,22 @@ mod tests {
                     ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
                     ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42,)
                 ]),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(
+                        None,
+                        ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                    component(
+                        None,
+                        ParamType::FixedArray(
+                            Box::new(ParamType::Tuple(vec![ParamType::Address])),
+                            42
+                        ),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
@@ -25,10 +25,16 @@ pub enum ParamType {
     Int(usize),
     /// Unsigned integer.
     Uint(usize),
+    /// Signed fixed-point decimal number with the given number of bits and decimals.
+    Fixed(usize, usize),
+    /// Unsigned fixed-point decimal number with the given number of bits and decimals.
+    Ufixed(usize, usize),
     /// Boolean.
     Bool,
     /// String.
     String,
+    /// External function pointer: an address followed by a function selector.
+    Function,
     /// Array of unknown size.
     Array(Box<ParamType>),
     /// Vector of bytes with fixed size.
@@ -81,8 +87,11 @@ mod tests {
         assert_eq!(format!("{}", ParamType::FixedBytes(32)), "bytes32".to_owned());
         assert_eq!(format!("{}", ParamType::Uint(256)), "uint256".to_owned());
         assert_eq!(format!("{}", ParamType::Int(64)), "int64".to_owned());
+        assert_eq!(format!("{}", ParamType::Fixed(128, 18)), "fixed128x18".to_owned());
+        assert_eq!(format!("{}", ParamType::Ufixed(8, 1)), "ufixed8x1".to_owned());
         assert_eq!(format!("{}", ParamType::Bool), "bool".to_owned());
         assert_eq!(format!("{}", ParamType::String), "string".to_owned());
+        assert_eq!(format!("{}", ParamType::Function), "function".to_owned());
         assert_eq!(format!("{}", ParamType::Array(Box::new(ParamType::Bool))), "bool[]".to_owned());
         assert_eq!(
             format!("{}", ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2)),
@@ -108,8 +117,10 @@ mod tests {
         assert!(!ParamType::FixedBytes(32).is_dynamic());
         assert!(!ParamType::Uint(256).is_dynamic());
         assert!(!ParamType::Int(64).is_dynamic());
+        assert!(!ParamType::Fixed(128, 18).is_dynamic());
         assert!(!ParamType::Bool.is_dynamic());
         assert!(ParamType::String.is_dynamic());
+        assert!(!ParamType::Function.is_dynamic());
         assert!(ParamType::Array(Box::new(ParamType::Bool)).is_dynamic());
         assert!(!ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2).is_dynamic());
         assert!(ParamType::FixedArray(Box::new(ParamType::String), 2).is_dynamic());
@@ -152,9 +152,12 @@ impl Reader {
             "bytes" => ParamType::Bytes,
             "bool" => ParamType::Bool,
             "string" => ParamType::String,
+            "function" => ParamType::Function,
             "int" => ParamType::Int(256),
             "tuple" => ParamType::Tuple(vec![]),
             "uint" => ParamType::Uint(256),
+            "fixed" => ParamType::Fixed(128, 18),
+            "ufixed" => ParamType::Ufixed(128, 18),
             s if s.starts_with("int") => {
                 let len = s[3..].parse().map_err(Error::ParseInt)?;
                 ParamType::Int(len)
@@ -167,6 +170,14 @@ impl Reader {
                 let len = s[5..].parse().map_err(Error::ParseInt)?;
                 ParamType::FixedBytes(len)
             }
+            s if s.starts_with("fixed") => {
+                let (len, decimals) = Reader::read_fixed(s, &s[5..])?;
+                ParamType::Fixed(len, decimals)
+            }
+            s if s.starts_with("ufixed") => {
+                let (len, decimals) = Reader::read_fixed(s, &s[6..])?;
+                ParamType::Ufixed(len, decimals)
+            }
             // As discussed in https://github.com/rust-ethereum/rethabi/issues/254,
             // any type that does not fit the above corresponds to a Solidity
             // `enum`, and as a result we treat it as a `uint8`. This is a unique
@@ -177,6 +188,19 @@ impl Reader {
 
         Ok(result)
     }
+
+    /// Parses the `MxN` suffix of a fixed-point type name, with `8 <= M <= 256`, `M % 8 == 0`
+    /// and `0 < N <= 80`.
+    fn read_fixed(name: &str, suffix: &str) -> Result<(usize, usize), Error> {
+        let (len, decimals) =
+            suffix.split_once('x').ok_or_else(|| Error::InvalidName(name.to_owned()))?;
+        let len: usize = len.parse().map_err(Error::ParseInt)?;
+        let decimals: usize = decimals.parse().map_err(Error::ParseInt)?;
+        if !(8..=256).contains(&len) || len % 8 != 0 || !(1..=80).contains(&decimals) {
+            return Err(Error::InvalidName(name.to_owned()));
+        }
+        Ok((len, decimals))
+    }
 }
 
 #[cfg(test)]
@@ -184,7 +208,7 @@ mod tests {
     use super::Reader;
     #[cfg(not(feature = "std"))]
     use crate::no_std_prelude::*;
-    use crate::ParamType;
+    use crate::{Error, ParamType};
 
     #[test]
     fn test_read_param() {
@@ -193,12 +217,33 @@ mod tests {
         assert_eq!(Reader::read("bytes32").unwrap(), ParamType::FixedBytes(32));
         assert_eq!(Reader::read("bool").unwrap(), ParamType::Bool);
         assert_eq!(Reader::read("string").unwrap(), ParamType::String);
+        assert_eq!(Reader::read("function").unwrap(), ParamType::Function);
         assert_eq!(Reader::read("int").unwrap(), ParamType::Int(256));
         assert_eq!(Reader::read("uint").unwrap(), ParamType::Uint(256));
         assert_eq!(Reader::read("int32").unwrap(), ParamType::Int(32));
         assert_eq!(Reader::read("uint32").unwrap(), ParamType::Uint(32));
     }
 
+    #[test]
+    fn test_read_fixed_param() {
+        assert_eq!(Reader::read("fixed").unwrap(), ParamType::Fixed(128, 18));
+        assert_eq!(Reader::read("ufixed").unwrap(), ParamType::Ufixed(128, 18));
+        assert_eq!(Reader::read("fixed128x18").unwrap(), ParamType::Fixed(128, 18));
+        assert_eq!(Reader::read("ufixed64x10").unwrap(), ParamType::Ufixed(64, 10));
+        assert_eq!(
+            Reader::read("fixed8x1[]").unwrap(),
+            ParamType::Array(Box::new(ParamType::Fixed(8, 1)))
+        );
+        assert_eq!(Reader::read("fixed256x80").unwrap(), ParamType::Fixed(256, 80));
+        assert!(Reader::read("fixed128").is_err());
+        assert!(Reader::read("ufixedx18").is_err());
+        for name in
+            ["fixed7x99", "fixed7x18", "fixed0x0", "fixed128x0", "ufixed512x18", "ufixed8x81"]
+        {
+            assert!(matches!(Reader::read(name), Err(Error::InvalidName(_))), "{name}");
+        }
+    }
+
     #[test]
     fn test_read_array_param() {
         assert_eq!(
@@ -29,8 +29,11 @@ impl Writer {
             ParamType::FixedBytes(len) => format!("bytes{len}"),
             ParamType::Int(len) => format!("int{len}"),
             ParamType::Uint(len) => format!("uint{len}"),
+            ParamType::Fixed(len, decimals) => format!("fixed{len}x{decimals}"),
+            ParamType::Ufixed(len, decimals) => format!("ufixed{len}x{decimals}"),
             ParamType::Bool => "bool".to_owned(),
             ParamType::String => "string".to_owned(),
+            ParamType::Function => "function".to_owned(),
             ParamType::FixedArray(ref param, len) => {
                 format!("{}[{len}]", Writer::write_for_abi(param, serialize_tuple_contents))
             }
@@ -67,8 +70,11 @@ mod tests {
         assert_eq!(Writer::write(&ParamType::FixedBytes(32)), "bytes32".to_owned());
         assert_eq!(Writer::write(&ParamType::Uint(256)), "uint256".to_owned());
         assert_eq!(Writer::write(&ParamType::Int(64)), "int64".to_owned());
+        assert_eq!(Writer::write(&ParamType::Fixed(128, 18)), "fixed128x18".to_owned());
+        assert_eq!(Writer::write(&ParamType::Ufixed(64, 10)), "ufixed64x10".to_owned());
         assert_eq!(Writer::write(&ParamType::Bool), "bool".to_owned());
         assert_eq!(Writer::write(&ParamType::String), "string".to_owned());
+        assert_eq!(Writer::write(&ParamType::Function), "function".to_owned());
         assert_eq!(
             Writer::write(&ParamType::Array(Box::new(ParamType::Bool))),
             "bool[]".to_owned()
@@ -0,0 +1,109 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Revert data of failed calls.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{decode, Bytes, ParamType, Token, Uint};
+
+/// Selector of `Error(string)`, used by `require` and `revert` with a reason.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of `Panic(uint256)`, used for failed assertions, overflows and the like.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Reason a call reverted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Revert {
+    /// `Error(string)` with the revert reason.
+    Error(String),
+    /// `Panic(uint256)` with the panic code, e.g. `0x11` for an arithmetic overflow.
+    Panic(Uint),
+    /// Any other revert data, e.g. a custom error or an empty revert.
+    Raw(Bytes),
+}
+
+impl Revert {
+    /// Decodes the data returned by a reverted call.
+    pub fn decode(data: &[u8]) -> Self {
+        let decoded = match data.split_at(data.len().min(4)) {
+            (selector, data) if selector == ERROR_SELECTOR => {
+                decode(&[ParamType::String], data).ok().and_then(|mut tokens| {
+                    tokens.pop().and_then(Token::into_string).map(Revert::Error)
+                })
+            }
+            (selector, data) if selector == PANIC_SELECTOR => decode(&[ParamType::Uint(256)], data)
+                .ok()
+                .and_then(|mut tokens| tokens.pop().and_then(Token::into_uint).map(Revert::Panic)),
+            _ => None,
+        };
+        decoded.unwrap_or_else(|| Revert::Raw(data.to_vec()))
+    }
+}
+
+impl fmt::Display for Revert {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Revert::Error(reason) => write!(f, "execution reverted: {reason}"),
+            Revert::Panic(code) => match u64::try_from(*code) {
+                Ok(code) => write!(f, "panic: {code:#x}"),
+                Err(_) => write!(f, "panic: 0x{code:x}"),
+            },
+            Revert::Raw(data) if data.is_empty() => write!(f, "execution reverted"),
+            Revert::Raw(data) => write!(f, "execution reverted: 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use crate::{Revert, Uint};
+
+    #[test]
+    fn decode_error() {
+        let data = hex!(
+            "
+			08c379a0
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000004
+			6e6f706500000000000000000000000000000000000000000000000000000000
+		"
+        );
+        let revert = Revert::decode(&data);
+        assert_eq!(revert, Revert::Error("nope".into()));
+        assert_eq!(revert.to_string(), "execution reverted: nope");
+    }
+
+    #[test]
+    fn decode_panic() {
+        let data = hex!(
+            "
+			4e487b71
+			0000000000000000000000000000000000000000000000000000000000000011
+		"
+        );
+        let revert = Revert::decode(&data);
+        assert_eq!(revert, Revert::Panic(Uint::from(0x11)));
+        assert_eq!(revert.to_string(), "panic: 0x11");
+    }
+
+    #[test]
+    fn decode_raw() {
+        assert_eq!(Revert::decode(&[]), Revert::Raw(vec![]));
+        assert_eq!(Revert::decode(&[]).to_string(), "execution reverted");
+        assert_eq!(Revert::decode(&hex!("deadbeef")), Revert::Raw(hex!("deadbeef").to_vec()));
+        assert_eq!(Revert::decode(&hex!("08c379a0")), Revert::Raw(hex!("08c379a0").to_vec()));
+    }
+}
@@ -12,7 +12,7 @@ use sha3::{Digest, Keccak256};
 use crate::no_std_prelude::*;
 use crate::{
     param_type::{ParamType, Writer},
-    Hash,
+    EventParam, Function, Hash, Param,
 };
 
 /// Returns the first four bytes of the Keccak-256 hash of the signature of the given params
@@ -29,10 +29,53 @@ pub fn long_signature(name: &str, params: &[ParamType]) -> Hash {
     result.into()
 }
 
-fn fill_signature(name: &str, params: &[ParamType], result: &mut [u8]) {
-    let types = params.iter().map(Writer::write).collect::<Vec<String>>().join(",");
+/// Returns the ERC-165 interface identifier of the given functions, the XOR of their selectors
+pub fn interface_id<'a, I>(functions: I) -> [u8; 4]
+where
+    I: IntoIterator<Item = &'a Function>,
+{
+    functions.into_iter().fold([0u8; 4], |mut id, function| {
+        for (byte, selector) in id.iter_mut().zip(function.short_signature()) {
+            *byte ^= selector;
+        }
+        id
+    })
+}
 
-    let data: Vec<u8> = From::from(format!("{name}({types})").as_str());
+/// Param whose type is part of the signature of a function, event or error.
+pub(crate) trait SignatureParam {
+    /// Type of the param.
+    fn kind(&self) -> &ParamType;
+}
+
+impl SignatureParam for ParamType {
+    fn kind(&self) -> &ParamType {
+        self
+    }
+}
+
+impl SignatureParam for Param {
+    fn kind(&self) -> &ParamType {
+        &self.kind
+    }
+}
+
+impl SignatureParam for EventParam {
+    fn kind(&self) -> &ParamType {
+        &self.kind
+    }
+}
+
+/// Returns the signature of the given params, e.g. `baz(uint32,bool)`
+pub(crate) fn signature_string<P: SignatureParam>(name: &str, params: &[P]) -> String {
+    let types =
+        params.iter().map(|param| Writer::write(param.kind())).collect::<Vec<String>>().join(",");
+
+    format!("{name}({types})")
+}
+
+fn fill_signature(name: &str, params: &[ParamType], result: &mut [u8]) {
+    let data: Vec<u8> = From::from(signature_string(name, params).as_str());
 
     result.copy_from_slice(&Keccak256::digest(data)[..result.len()])
 }
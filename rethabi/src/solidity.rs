@@ -0,0 +1,395 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solidity interface source of a contract.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{param_type::Writer, Contract, InternalType, ParamType, StateMutability, TupleParam};
+
+/// Indentation of the interface body.
+const INDENT: &str = "    ";
+
+/// Struct declared by the interface.
+struct Struct {
+    name: String,
+    kind: ParamType,
+    fields: Vec<String>,
+}
+
+/// Structs reconstructed from the tuple params of the contract.
+#[derive(Default)]
+struct Structs(Vec<Struct>);
+
+impl Structs {
+    /// Returns the name of the struct of a tuple type, declaring the struct if needed.
+    ///
+    /// The name is taken from the `internalType` if given, otherwise a struct with the same
+    /// components declared for another param is reused.
+    fn resolve(
+        &mut self,
+        kind: &ParamType,
+        internal_type: Option<&str>,
+        components: &[TupleParam],
+    ) -> String {
+        let hint =
+            internal_type.map(InternalType::parse).and_then(|internal_type| match internal_type {
+                InternalType::Struct { ty, .. } => Some(strip_array_suffix(&ty).to_owned()),
+                _ => None,
+            });
+
+        let existing = self
+            .0
+            .iter()
+            .find(|s| s.kind == *kind && hint.as_ref().map_or(true, |hint| s.name == *hint));
+        if let Some(existing) = existing {
+            return existing.name.clone();
+        }
+
+        let mut n = 1;
+        let mut name = hint.clone().unwrap_or_else(|| format!("Struct{n}"));
+        let base = hint.unwrap_or_else(|| "Struct".to_owned());
+        while self.0.iter().any(|s| s.name == name) {
+            n += 1;
+            name = format!("{base}{n}");
+        }
+
+        // Reserve the name before resolving the components, which may declare structs too.
+        let index = self.0.len();
+        self.0.push(Struct { name: name.clone(), kind: kind.clone(), fields: vec![] });
+
+        let types = match kind {
+            ParamType::Tuple(types) => types.as_slice(),
+            _ => &[],
+        };
+        let fields = types
+            .iter()
+            .enumerate()
+            .map(|(i, kind)| match components.get(i) {
+                Some(component) => {
+                    let ty = self.ty(
+                        &component.kind,
+                        component.internal_type.as_deref(),
+                        component.named_components(),
+                    );
+                    match component.name.as_deref() {
+                        Some(name) if !name.is_empty() => format!("{ty} {name};"),
+                        _ => format!("{ty} field{i};"),
+                    }
+                }
+                None => format!("{} field{i};", self.ty(kind, None, &[])),
+            })
+            .collect();
+        self.0[index].fields = fields;
+
+        name
+    }
+
+    /// Returns the Solidity type of a param.
+    ///
+    /// Enums and user-defined value types are declared with the type they are encoded as, and
+    /// contract types as `address`, as their definitions are not part of the ABI.
+    fn ty(
+        &mut self,
+        kind: &ParamType,
+        internal_type: Option<&str>,
+        components: &[TupleParam],
+    ) -> String {
+        match kind {
+            ParamType::Array(inner) => format!("{}[]", self.ty(inner, internal_type, components)),
+            ParamType::FixedArray(inner, len) => {
+                format!("{}[{len}]", self.ty(inner, internal_type, components))
+            }
+            ParamType::Tuple(_) => self.resolve(kind, internal_type, components),
+            // Any external function type is encoded as its address and selector.
+            ParamType::Function => "function() external".to_owned(),
+            _ => Writer::write(kind),
+        }
+    }
+}
+
+fn strip_array_suffix(ty: &str) -> &str {
+    ty.find('[').map_or(ty, |bracket| &ty[..bracket])
+}
+
+/// Whether a param of this type needs a data location in a function.
+fn has_location(kind: &ParamType) -> bool {
+    matches!(
+        kind,
+        ParamType::Bytes
+            | ParamType::String
+            | ParamType::Array(_)
+            | ParamType::FixedArray(..)
+            | ParamType::Tuple(_)
+    )
+}
+
+impl Contract {
+    /// Renders the contract as the source of a Solidity interface named `name`.
+    ///
+    /// Structs are reconstructed from the components of tuple params and named after their
+    /// `internalType`. Tuples without one reuse a struct with the same components, or get a
+    /// generated name.
+    pub fn to_solidity_interface(&self, name: &str) -> String {
+        let mut structs = Structs::default();
+        let mut events = Vec::new();
+        let mut errors = Vec::new();
+        let mut functions = Vec::new();
+
+        // Functions first, so structs are declared in the order functions use them.
+        for function in self.functions() {
+            let inputs = function
+                .inputs
+                .iter()
+                .map(|p| {
+                    let ty = structs.ty(&p.kind, p.internal_type.as_deref(), p.named_components());
+                    let location = if has_location(&p.kind) { " calldata" } else { "" };
+                    declaration(&format!("{ty}{location}"), &p.name)
+                })
+                .collect::<Vec<_>>();
+            let outputs = function
+                .outputs
+                .iter()
+                .map(|p| {
+                    let ty = structs.ty(&p.kind, p.internal_type.as_deref(), p.named_components());
+                    let location = if has_location(&p.kind) { " memory" } else { "" };
+                    declaration(&format!("{ty}{location}"), &p.name)
+                })
+                .collect::<Vec<_>>();
+
+            let mut line = format!("function {}({}) external", function.name, inputs.join(", "));
+            match function.state_mutability {
+                StateMutability::Pure => line.push_str(" pure"),
+                StateMutability::View => line.push_str(" view"),
+                StateMutability::Payable => line.push_str(" payable"),
+                StateMutability::NonPayable => {}
+            }
+            if !outputs.is_empty() {
+                line.push_str(&format!(" returns ({})", outputs.join(", ")));
+            }
+            line.push(';');
+            functions.push(line);
+        }
+
+        for error in self.errors() {
+            let inputs = error
+                .inputs
+                .iter()
+                .map(|p| {
+                    let ty = structs.ty(&p.kind, p.internal_type.as_deref(), p.named_components());
+                    declaration(&ty, &p.name)
+                })
+                .collect::<Vec<_>>();
+            errors.push(format!("error {}({});", error.name, inputs.join(", ")));
+        }
+
+        for event in self.events() {
+            let inputs = event
+                .inputs
+                .iter()
+                .map(|p| {
+                    let ty = structs.ty(&p.kind, p.internal_type.as_deref(), p.named_components());
+                    let indexed = if p.indexed { " indexed" } else { "" };
+                    declaration(&format!("{ty}{indexed}"), &p.name)
+                })
+                .collect::<Vec<_>>();
+            let anonymous = if event.anonymous { " anonymous" } else { "" };
+            events.push(format!("event {}({}){anonymous};", event.name, inputs.join(", ")));
+        }
+
+        if self.receive.is_some() {
+            functions.push("receive() external payable;".to_owned());
+        }
+        if let Some(fallback) = &self.fallback {
+            let payable = match fallback.state_mutability {
+                StateMutability::Payable => " payable",
+                _ => "",
+            };
+            functions.push(format!("fallback() external{payable};"));
+        }
+
+        let mut sections = structs
+            .0
+            .iter()
+            .map(|s| {
+                let mut lines = vec![format!("struct {} {{", s.name)];
+                lines.extend(s.fields.iter().map(|field| format!("{INDENT}{field}")));
+                lines.push("}".to_owned());
+                lines
+            })
+            .collect::<Vec<_>>();
+        sections.extend([events, errors, functions].into_iter().filter(|s| !s.is_empty()));
+
+        let mut out = String::from("// SPDX-License-Identifier: UNLICENSED\n");
+        out.push_str("pragma solidity ^0.8.4;\n\n");
+        out.push_str(&format!("interface {name} {{\n"));
+        for (i, section) in sections.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            for line in section {
+                out.push_str(INDENT);
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn declaration(ty: &str, name: &str) -> String {
+    match name {
+        "" => ty.to_owned(),
+        name => format!("{ty} {name}"),
+    }
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use crate::Contract;
+
+    #[test]
+    fn to_solidity_interface() {
+        let json = r#"[
+		{
+			"type": "function",
+			"name": "submit",
+			"inputs": [
+				{
+					"name": "order",
+					"type": "tuple",
+					"internalType": "struct Market.Order",
+					"components": [
+						{ "name": "maker", "type": "address", "internalType": "address" },
+						{ "name": "side", "type": "uint8", "internalType": "enum Market.Side" },
+						{
+							"name": "legs",
+							"type": "tuple[]",
+							"internalType": "struct Market.Leg[]",
+							"components": [
+								{ "name": "id", "type": "uint64", "internalType": "uint64" },
+								{ "name": "token", "type": "address", "internalType": "contract IERC20" }
+							]
+						}
+					]
+				},
+				{ "name": "", "type": "bytes", "internalType": "bytes" }
+			],
+			"outputs": [{ "name": "id", "type": "bytes32", "internalType": "bytes32" }],
+			"stateMutability": "payable"
+		},
+		{
+			"type": "function",
+			"name": "orders",
+			"inputs": [{ "name": "id", "type": "bytes32", "internalType": "bytes32" }],
+			"outputs": [
+				{
+					"name": "",
+					"type": "tuple",
+					"internalType": "struct Market.Order",
+					"components": [
+						{ "name": "maker", "type": "address", "internalType": "address" },
+						{ "name": "side", "type": "uint8", "internalType": "enum Market.Side" },
+						{
+							"name": "legs",
+							"type": "tuple[]",
+							"internalType": "struct Market.Leg[]",
+							"components": [
+								{ "name": "id", "type": "uint64", "internalType": "uint64" },
+								{ "name": "token", "type": "address", "internalType": "contract IERC20" }
+							]
+						}
+					]
+				}
+			],
+			"stateMutability": "view"
+		},
+		{
+			"type": "event",
+			"name": "Submitted",
+			"inputs": [
+				{ "name": "id", "type": "bytes32", "indexed": true },
+				{
+					"name": "leg",
+					"type": "tuple",
+					"indexed": false,
+					"components": [
+						{ "name": "id", "type": "uint64" },
+						{ "name": "token", "type": "address" }
+					]
+				},
+				{
+					"name": "fill",
+					"type": "tuple",
+					"indexed": false,
+					"internalType": "struct Market.Fill",
+					"components": [{ "name": "amount", "type": "uint256" }]
+				},
+				{
+					"name": "fees",
+					"type": "tuple",
+					"indexed": false,
+					"components": [{ "name": "bps", "type": "uint16" }]
+				}
+			],
+			"anonymous": false
+		},
+		{
+			"type": "event",
+			"name": "Raw",
+			"inputs": [{ "name": "data", "type": "bytes", "indexed": false }],
+			"anonymous": true
+		},
+		{
+			"type": "error",
+			"name": "Rejected",
+			"inputs": [{ "name": "reason", "type": "string", "internalType": "string" }]
+		},
+		{ "type": "receive", "stateMutability": "payable" },
+		{ "type": "fallback", "stateMutability": "payable" }
+	]"#;
+
+        let contract: Contract = serde_json::from_str(json).unwrap();
+        let expected = "// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.4;
+
+interface IMarket {
+    struct Order {
+        address maker;
+        uint8 side;
+        Leg[] legs;
+    }
+
+    struct Leg {
+        uint64 id;
+        address token;
+    }
+
+    struct Fill {
+        uint256 amount;
+    }
+
+    struct Struct1 {
+        uint16 bps;
+    }
+
+    event Submitted(bytes32 indexed id, Leg leg, Fill fill, Struct1 fees);
+    event Raw(bytes data) anonymous;
+
+    error Rejected(string reason);
+
+    function submit(Order calldata order, bytes calldata) external payable returns (bytes32 id);
+    function orders(bytes32 id) external view returns (Order memory);
+    receive() external payable;
+    fallback() external payable;
+}
+";
+        assert_eq!(contract.to_solidity_interface("IMarket"), expected);
+    }
+}
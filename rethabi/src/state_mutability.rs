@@ -1,9 +1,11 @@
+use core::fmt;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Whether a function modifies or reads blockchain state
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum StateMutability {
     /// Specified not to read blockchain state
     #[cfg_attr(feature = "serde", serde(rename = "pure"))]
@@ -13,15 +15,21 @@ pub enum StateMutability {
     View,
     /// Function does not accept Ether - the default
     #[cfg_attr(feature = "serde", serde(rename = "nonpayable"))]
+    #[default]
     NonPayable,
     /// Function accepts Ether
     #[cfg_attr(feature = "serde", serde(rename = "payable"))]
     Payable,
 }
 
-impl Default for StateMutability {
-    fn default() -> Self {
-        Self::NonPayable
+impl fmt::Display for StateMutability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StateMutability::Pure => write!(f, "pure"),
+            StateMutability::View => write!(f, "view"),
+            StateMutability::NonPayable => write!(f, "nonpayable"),
+            StateMutability::Payable => write!(f, "payable"),
+        }
     }
 }
 
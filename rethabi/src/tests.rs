@@ -17,6 +17,8 @@ use serde_json::Value;
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
+#[cfg(feature = "serde")]
+use crate::TupleParam;
 use crate::{decode, encode, ParamType, Token, Uint};
 
 #[cfg(feature = "serde")]
@@ -36,6 +38,15 @@ where
     assert_eq!(canon, &de);
 }
 
+#[cfg(feature = "serde")]
+pub(crate) fn component(
+    name: Option<&str>,
+    kind: ParamType,
+    components: Vec<TupleParam>,
+) -> TupleParam {
+    TupleParam { name: name.map(ToOwned::to_owned), kind, internal_type: None, components }
+}
+
 macro_rules! test_encode_decode {
     (name: $name:tt, types: $types:expr, tokens: $tokens:expr, data: $data:tt) => {
         paste::item! {
@@ -165,6 +176,41 @@ test_encode_decode! {
     data: "0000000000000000000000000000000000000000000000000000000000000004"
 }
 
+// test fixed
+test_encode_decode! {
+    name: fixed,
+    types: [ParamType::Fixed(128, 18)],
+    tokens: [Token::Fixed(Uint::from(1_500_000_000_000_000_000u64).wrapping_neg(), 18)],
+    data: "ffffffffffffffffffffffffffffffffffffffffffffffffeb2eedf284ea0000"
+}
+test_encode_decode! {
+    name: ufixed,
+    types: [ParamType::Ufixed(64, 2)],
+    tokens: [Token::Ufixed(Uint::from(150), 2)],
+    data: "0000000000000000000000000000000000000000000000000000000000000096"
+}
+
+// test function
+test_encode_decode! {
+    name: function,
+    types: [ParamType::Function],
+    tokens: [Token::Function([0x11u8; 20].into(), [0x12, 0x34, 0x56, 0x78])],
+    data: "1111111111111111111111111111111111111111123456780000000000000000"
+}
+test_encode_decode! {
+    name: functions,
+    types: [ParamType::Array(Box::new(ParamType::Function))],
+    tokens: [Token::Array(vec![
+        Token::Function([0x11u8; 20].into(), [0x12, 0x34, 0x56, 0x78]),
+        Token::Function([0x22u8; 20].into(), [0xab, 0xcd, 0xef, 0x01])
+    ])],
+    data: "
+		0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000002
+		1111111111111111111111111111111111111111123456780000000000000000
+		2222222222222222222222222222222222222222abcdef010000000000000000"
+}
+
 // test bool
 test_encode_decode! {
     name: bool,
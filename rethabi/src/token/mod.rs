@@ -27,7 +27,7 @@ use crate::no_std_prelude::*;
 use core::cmp::Ordering::{Equal, Less};
 
 #[cfg(feature = "serde")]
-use crate::{util::array_to_u256, Error, ParamType};
+use crate::{no_std_prelude::Cow, util::array_to_u256, Error, ParamType, Uint};
 
 /// This trait should be used to parse string values as tokens.
 #[cfg(feature = "serde")]
@@ -48,6 +48,17 @@ pub trait Tokenizer {
             }
             ParamType::Uint(_) => Self::tokenize_uint(value).map(array_to_u256).map(Token::Uint),
             ParamType::Int(_) => Self::tokenize_int(value).map(array_to_u256).map(Token::Int),
+            ParamType::Fixed(_, decimals) => Self::tokenize_fixed(value, decimals)
+                .map(|fixed| Token::Fixed(array_to_u256(fixed), decimals)),
+            ParamType::Ufixed(_, decimals) => Self::tokenize_ufixed(value, decimals)
+                .map(|fixed| Token::Ufixed(array_to_u256(fixed), decimals)),
+            ParamType::Function => Self::tokenize_function(value).map(|function| {
+                let mut address = [0u8; 20];
+                address.copy_from_slice(&function[..20]);
+                let mut selector = [0u8; 4];
+                selector.copy_from_slice(&function[20..]);
+                Token::Function(address.into(), selector)
+            }),
             ParamType::Array(ref p) => Self::tokenize_array(value, p).map(Token::Array),
             ParamType::FixedArray(ref p, len) => {
                 Self::tokenize_fixed_array(value, p, len).map(Token::FixedArray)
@@ -89,7 +100,7 @@ pub trait Tokenizer {
         let mut last_is_array = false;
 
         let mut params = param.iter();
-        for (pos, ch) in value.chars().enumerate() {
+        for (pos, ch) in value.char_indices() {
             match ch {
                 '[' if !ignore => {
                     if array_nested == 0 {
@@ -189,7 +200,7 @@ pub trait Tokenizer {
         let mut tuple_nested = 0isize;
         let mut tuple_item_start = 1;
         let mut last_is_tuple = false;
-        for (i, ch) in value.chars().enumerate() {
+        for (i, ch) in value.char_indices() {
             match ch {
                 '(' if !ignore => {
                     if tuple_nested == 0 {
@@ -282,11 +293,76 @@ pub trait Tokenizer {
 
     /// Tries to parse a value as signed integer.
     fn tokenize_int(value: &str) -> Result<[u8; 32], Error>;
+
+    /// Tries to parse a value as an external function pointer, given either as
+    /// `address.selector` or as the raw 24 bytes.
+    fn tokenize_function(value: &str) -> Result<[u8; 24], Error> {
+        let value = value.strip_prefix("0x").unwrap_or(value);
+        let mut function = [0u8; 24];
+        match value.split_once('.') {
+            Some((address, selector)) => {
+                function[..20].copy_from_slice(&Self::tokenize_address(address)?);
+                let selector = selector.strip_prefix("0x").unwrap_or(selector);
+                function[20..].copy_from_slice(&Self::tokenize_fixed_bytes(selector, 4)?);
+            }
+            None => function.copy_from_slice(&Self::tokenize_fixed_bytes(value, 24)?),
+        }
+        Ok(function)
+    }
+
+    /// Tries to parse a decimal value, such as `-1.5`, as a signed fixed-point number.
+    fn tokenize_fixed(value: &str, decimals: usize) -> Result<[u8; 32], Error> {
+        let (negative, abs) = match value.strip_prefix('-') {
+            Some(abs) => (true, parse_decimal(abs, decimals)?),
+            None => (false, parse_decimal(value, decimals)?),
+        };
+        let max = Uint::MAX / Uint::from(2u64);
+        let fixed = if negative {
+            if abs > max + Uint::from(1u64) {
+                return Err(Error::Other(Cow::Borrowed("fixed parse error: Underflow")));
+            }
+            abs.wrapping_neg()
+        } else {
+            if abs > max {
+                return Err(Error::Other(Cow::Borrowed("fixed parse error: Overflow")));
+            }
+            abs
+        };
+        Ok(fixed.to_be_bytes())
+    }
+
+    /// Tries to parse a decimal value, such as `1.5`, as an unsigned fixed-point number.
+    fn tokenize_ufixed(value: &str, decimals: usize) -> Result<[u8; 32], Error> {
+        parse_decimal(value, decimals).map(|ufixed| ufixed.to_be_bytes())
+    }
+}
+
+/// Parses an unsigned decimal number and scales it by `10^decimals`.
+///
+/// Fails if the number has more significant fractional digits than `decimals`.
+#[cfg(feature = "serde")]
+fn parse_decimal(value: &str, decimals: usize) -> Result<Uint, Error> {
+    let (integer, fract) = match value.split_once('.') {
+        Some((_, "")) => return Err(Error::InvalidData),
+        Some((integer, fract)) => (integer, fract),
+        None => (value, ""),
+    };
+    if integer.is_empty() || !integer.chars().chain(fract.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(Error::InvalidData);
+    }
+
+    let fract = fract.trim_end_matches('0');
+    if fract.len() > decimals {
+        return Err(Error::Other(Cow::Borrowed("fixed parse error: too many decimals")));
+    }
+
+    let digits = format!("{integer}{fract:0<decimals$}");
+    Ok(Uint::from_str_radix(&digits, 10)?)
 }
 
 #[cfg(all(test, feature = "full-serde"))]
 mod test {
-    use super::{LenientTokenizer, ParamType, Tokenizer};
+    use super::{LenientTokenizer, ParamType, StrictTokenizer, Tokenizer};
     use crate::{Token, Uint};
 
     #[test]
@@ -337,6 +413,79 @@ mod test {
         );
     }
 
+    #[test]
+    fn tokenize_fixed() {
+        assert_eq!(
+            LenientTokenizer::tokenize(&ParamType::Fixed(128, 18), "-1.5").unwrap(),
+            Token::Fixed(Uint::from(1_500_000_000_000_000_000u64).wrapping_neg(), 18)
+        );
+        assert_eq!(
+            LenientTokenizer::tokenize(&ParamType::Ufixed(128, 2), "3").unwrap(),
+            Token::Ufixed(Uint::from(300), 2)
+        );
+        assert_eq!(
+            StrictTokenizer::tokenize(&ParamType::Ufixed(128, 2), "0.070").unwrap(),
+            Token::Ufixed(Uint::from(7), 2)
+        );
+        assert_eq!(
+            StrictTokenizer::tokenize(
+                &ParamType::Array(Box::new(ParamType::Fixed(8, 1))),
+                "[1.2,-0.3]"
+            )
+            .unwrap(),
+            Token::Array(vec![
+                Token::Fixed(Uint::from(12), 1),
+                Token::Fixed(Uint::from(3).wrapping_neg(), 1),
+            ])
+        );
+
+        assert!(StrictTokenizer::tokenize(&ParamType::Ufixed(128, 2), "0.001").is_err());
+        assert!(StrictTokenizer::tokenize(&ParamType::Ufixed(128, 2), "-1").is_err());
+        assert!(StrictTokenizer::tokenize(&ParamType::Ufixed(128, 2), "1.").is_err());
+        assert!(StrictTokenizer::tokenize(&ParamType::Ufixed(128, 2), ".1").is_err());
+        assert!(StrictTokenizer::tokenize(&ParamType::Fixed(128, 2), "1.2.3").is_err());
+    }
+
+    #[test]
+    fn tokenize_function() {
+        let expected = Token::Function([0x11u8; 20].into(), [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(
+            StrictTokenizer::tokenize(
+                &ParamType::Function,
+                "0x1111111111111111111111111111111111111111.0x12345678"
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            LenientTokenizer::tokenize(
+                &ParamType::Function,
+                "1111111111111111111111111111111111111111.12345678"
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            StrictTokenizer::tokenize(
+                &ParamType::Function,
+                "0x111111111111111111111111111111111111111112345678"
+            )
+            .unwrap(),
+            expected
+        );
+
+        assert!(StrictTokenizer::tokenize(
+            &ParamType::Function,
+            "1111111111111111111111111111111111111111.123456"
+        )
+        .is_err());
+        assert!(StrictTokenizer::tokenize(
+            &ParamType::Function,
+            "1111111111111111111111111111111111111111"
+        )
+        .is_err());
+    }
+
     #[test]
     fn tuple_array_nested() {
         assert_eq!(
@@ -356,4 +505,17 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn tokenize_non_ascii_strings() {
+        assert_eq!(
+            LenientTokenizer::tokenize_array("[äb,c]", &ParamType::String).unwrap(),
+            vec![Token::String("äb".to_owned()), Token::String("c".to_owned())]
+        );
+        assert_eq!(
+            LenientTokenizer::tokenize_struct("(ü,1)", &[ParamType::String, ParamType::Uint(8)])
+                .unwrap(),
+            vec![Token::String("ü".to_owned()), Token::Uint(Uint::from(1u64))]
+        );
+    }
 }
@@ -46,6 +46,18 @@ pub enum Token {
     ///
     /// solidity name: uint
     Uint(Uint),
+    /// Signed fixed-point decimal number.
+    ///
+    /// solidity name eg.: fixed128x18
+    /// Holds the value scaled by `10^decimals` followed by the number of decimals.
+    /// Encoded in the same way as int.
+    Fixed(Int, usize),
+    /// Unsigned fixed-point decimal number.
+    ///
+    /// solidity name eg.: ufixed128x18
+    /// Holds the value scaled by `10^decimals` followed by the number of decimals.
+    /// Encoded in the same way as uint.
+    Ufixed(Uint, usize),
     /// Boolean value.
     ///
     /// solidity name: bool
@@ -56,6 +68,12 @@ pub enum Token {
     /// solidity name: string
     /// Encoded in the same way as bytes. Must be utf8 compliant.
     String(String),
+    /// External function pointer.
+    ///
+    /// solidity name: function
+    /// Holds the contract address followed by the function selector.
+    /// Encoded to right padded `[0u8; 32]`, in the same way as bytes24.
+    Function(Address, [u8; 4]),
     /// Array with known size.
     ///
     /// solidity name eg.: `int[3]`, `bool[3]`, `address[][8]`
@@ -81,6 +99,17 @@ impl fmt::Display for Token {
                 write!(f, "{}", hex::encode(bytes))
             }
             Token::Uint(ref i) | Token::Int(ref i) => write!(f, "{i:x}"),
+            Token::Function(ref address, ref selector) => {
+                write!(f, "{address:x}.{}", hex::encode(selector))
+            }
+            Token::Fixed(ref i, decimals) => {
+                if i.bit(255) {
+                    write!(f, "-{}", format_decimal(i.wrapping_neg(), decimals))
+                } else {
+                    write!(f, "{}", format_decimal(*i, decimals))
+                }
+            }
+            Token::Ufixed(ref i, decimals) => write!(f, "{}", format_decimal(*i, decimals)),
             Token::Array(ref arr) | Token::FixedArray(ref arr) => {
                 let s = arr.iter().map(|ref t| format!("{t}")).collect::<Vec<String>>().join(",");
 
@@ -95,6 +124,22 @@ impl fmt::Display for Token {
     }
 }
 
+/// Formats an unsigned value scaled by `10^decimals` as an exact decimal number, omitting
+/// trailing zeros of the fractional part.
+fn format_decimal(value: Uint, decimals: usize) -> String {
+    let digits = value.to_string();
+    if decimals == 0 {
+        return digits;
+    }
+
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (integer, fract) = digits.split_at(digits.len() - decimals);
+    match fract.trim_end_matches('0') {
+        "" => integer.to_owned(),
+        fract => format!("{integer}.{fract}"),
+    }
+}
+
 impl Token {
     /// Check whether the type of the token matches the given parameter type.
     ///
@@ -110,8 +155,15 @@ impl Token {
             Token::Uint(_) => {
                 matches!(*param_type, ParamType::Uint(_))
             }
+            Token::Fixed(_, decimals) => {
+                matches!(*param_type, ParamType::Fixed(_, d) if d == decimals)
+            }
+            Token::Ufixed(_, decimals) => {
+                matches!(*param_type, ParamType::Ufixed(_, d) if d == decimals)
+            }
             Token::Bool(_) => *param_type == ParamType::Bool,
             Token::String(_) => *param_type == ParamType::String,
+            Token::Function(..) => *param_type == ParamType::Function,
             Token::FixedBytes(ref bytes) => {
                 if let ParamType::FixedBytes(size) = *param_type {
                     size >= bytes.len()
@@ -183,6 +235,30 @@ impl Token {
         }
     }
 
+    /// Converts token to...
+    pub fn into_fixed(self) -> Option<(Int, usize)> {
+        match self {
+            Token::Fixed(int, decimals) => Some((int, decimals)),
+            _ => None,
+        }
+    }
+
+    /// Converts token to...
+    pub fn into_ufixed(self) -> Option<(Uint, usize)> {
+        match self {
+            Token::Ufixed(uint, decimals) => Some((uint, decimals)),
+            _ => None,
+        }
+    }
+
+    /// Converts token to...
+    pub fn into_function(self) -> Option<(Address, [u8; 4])> {
+        match self {
+            Token::Function(address, selector) => Some((address, selector)),
+            _ => None,
+        }
+    }
+
     /// Converts token to...
     pub fn into_bool(self) -> Option<bool> {
         match self {
@@ -245,7 +321,7 @@ impl Token {
 mod tests {
     #[cfg(not(feature = "std"))]
     use crate::no_std_prelude::*;
-    use crate::{ParamType, Token, Uint};
+    use crate::{Address, ParamType, Token, Uint};
 
     #[test]
     fn test_type_check() {
@@ -289,6 +365,22 @@ mod tests {
             vec![ParamType::FixedBytes(3)],
         );
 
+        assert_type_check(
+            vec![Token::Fixed(Uint::from(15), 1), Token::Ufixed(Uint::from(15), 18)],
+            vec![ParamType::Fixed(8, 1), ParamType::Ufixed(128, 18)],
+        );
+        assert_not_type_check(vec![Token::Fixed(Uint::from(15), 1)], vec![ParamType::Fixed(8, 2)]);
+        assert_not_type_check(vec![Token::Ufixed(Uint::from(15), 1)], vec![ParamType::Fixed(8, 1)]);
+
+        assert_type_check(
+            vec![Token::Function([0u8; 20].into(), [0x12, 0x34, 0x56, 0x78])],
+            vec![ParamType::Function],
+        );
+        assert_not_type_check(
+            vec![Token::Function([0u8; 20].into(), [0x12, 0x34, 0x56, 0x78])],
+            vec![ParamType::FixedBytes(24)],
+        );
+
         assert_type_check(
             vec![Token::Array(vec![Token::Bool(false), Token::Bool(true)])],
             vec![ParamType::Array(Box::new(ParamType::Bool))],
@@ -320,6 +412,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fixed_display() {
+        assert_eq!(Token::Ufixed(Uint::from(15), 1).to_string(), "1.5");
+        assert_eq!(Token::Ufixed(Uint::from(1_500_000_000_000_000_000u64), 18).to_string(), "1.5");
+        assert_eq!(Token::Ufixed(Uint::from(2_000_000u64), 6).to_string(), "2");
+        assert_eq!(Token::Ufixed(Uint::from(7), 3).to_string(), "0.007");
+        assert_eq!(Token::Ufixed(Uint::from(7), 0).to_string(), "7");
+        assert_eq!(Token::Fixed(Uint::from(15).wrapping_neg(), 1).to_string(), "-1.5");
+        assert_eq!(Token::Fixed(Uint::from(25).wrapping_neg(), 3).to_string(), "-0.025");
+        assert_eq!(Token::Fixed(Uint::ZERO, 18).to_string(), "0");
+    }
+
+    #[test]
+    fn test_function_display() {
+        let address: Address = "0x1111111111111111111111111111111111111111".parse().unwrap();
+        assert_eq!(
+            Token::Function(address, [0x12, 0x34, 0x56, 0x78]).to_string(),
+            "1111111111111111111111111111111111111111.12345678"
+        );
+    }
+
     #[test]
     fn test_is_dynamic() {
         assert!(!Token::Address("0000000000000000000000000000000000000000".parse().unwrap())
@@ -10,8 +10,12 @@
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{param_type::Writer, ParamType};
+#[cfg(feature = "serde")]
+use crate::param_type::Writer;
+use crate::ParamType;
+#[cfg(feature = "serde")]
 use core::fmt;
+#[cfg(feature = "serde")]
 use serde::{
     de::{Error, MapAccess, Visitor},
     ser::SerializeMap,
@@ -29,8 +33,27 @@ pub struct TupleParam {
 
     /// Additional Internal type.
     pub internal_type: Option<String>,
+
+    /// Named components of the tuple, if the param is a tuple or an array of tuples.
+    ///
+    /// Ignored unless they describe the tuple of `kind`, see [`TupleParam::named_components`].
+    pub components: Vec<TupleParam>,
 }
 
+impl TupleParam {
+    /// Creates a component without an `internalType` or named components.
+    pub fn new(name: Option<String>, kind: ParamType) -> Self {
+        TupleParam { name, kind, internal_type: None, components: vec![] }
+    }
+
+    /// Returns the named components of the tuple of the param, or none if `components` does
+    /// not describe the tuple of `kind`.
+    pub fn named_components(&self) -> &[TupleParam] {
+        crate::param::named_components(&self.kind, &self.components)
+    }
+}
+
+#[cfg(feature = "serde")]
 impl<'a> Deserialize<'a> for TupleParam {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -40,8 +63,10 @@ impl<'a> Deserialize<'a> for TupleParam {
     }
 }
 
+#[cfg(feature = "serde")]
 struct TupleParamVisitor;
 
+#[cfg(feature = "serde")]
 impl<'a> Visitor<'a> for TupleParamVisitor {
     type Value = TupleParam;
 
@@ -90,11 +115,12 @@ impl<'a> Visitor<'a> for TupleParamVisitor {
         }
 
         let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-        crate::param::set_tuple_components(&mut kind, components)?;
-        Ok(TupleParam { name, kind, internal_type })
+        let components = crate::param::set_tuple_components(&mut kind, components)?;
+        Ok(TupleParam { name, kind, internal_type, components })
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for TupleParam {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -108,20 +134,17 @@ impl Serialize for TupleParam {
             map.serialize_entry("name", name)?;
         }
         map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
-        if let Some(inner_tuple) = crate::param::inner_tuple(&self.kind) {
-            map.serialize_key("components")?;
-            map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple))?;
-        }
+        crate::param::serialize_components(&mut map, &self.kind, &self.components)?;
         map.end()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use crate::no_std_prelude::*;
     use crate::{
-        tests::{assert_json_eq, assert_ser_de},
+        tests::{assert_json_eq, assert_ser_de, component},
         ParamType, TupleParam,
     };
 
@@ -134,14 +157,7 @@ mod tests {
 
         let deserialized: TupleParam = serde_json::from_str(s).unwrap();
 
-        assert_eq!(
-            deserialized,
-            TupleParam {
-                name: Some("foo".to_owned()),
-                kind: ParamType::Address,
-                internal_type: None
-            }
-        );
+        assert_eq!(deserialized, TupleParam::new(Some("foo".to_owned()), ParamType::Address));
 
         assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
     }
@@ -161,7 +177,8 @@ mod tests {
             TupleParam {
                 name: Some("foo".to_owned()),
                 kind: ParamType::Address,
-                internal_type: Some("struct Verifier.Proof".to_string())
+                internal_type: Some("struct Verifier.Proof".to_string()),
+                components: vec![],
             }
         );
 
@@ -176,10 +193,7 @@ mod tests {
 
         let deserialized: TupleParam = serde_json::from_str(s).unwrap();
 
-        assert_eq!(
-            deserialized,
-            TupleParam { name: None, kind: ParamType::Address, internal_type: None }
-        );
+        assert_eq!(deserialized, TupleParam::new(None, ParamType::Address));
 
         assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
     }
@@ -213,7 +227,15 @@ mod tests {
                     ParamType::Uint(48),
                     ParamType::Tuple(vec![ParamType::Address])
                 ]),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(
+                        None,
+                        ParamType::Tuple(vec![ParamType::Address]),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
@@ -252,7 +274,15 @@ mod tests {
                     ParamType::Uint(48),
                     ParamType::Tuple(vec![ParamType::Address])
                 ]),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(Some("amount"), ParamType::Uint(48), vec![]),
+                    component(
+                        Some("things"),
+                        ParamType::Tuple(vec![ParamType::Address]),
+                        vec![component(Some("baseTupleParam"), ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
@@ -287,7 +317,12 @@ mod tests {
                     ParamType::Address,
                     ParamType::Address
                 ]))),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(None, ParamType::Address, vec![]),
+                    component(None, ParamType::Address, vec![]),
+                ],
             }
         );
 
@@ -316,7 +351,11 @@ mod tests {
                 kind: ParamType::Array(Box::new(ParamType::Array(Box::new(ParamType::Tuple(
                     vec![ParamType::Uint(8), ParamType::Uint(16),]
                 ))))),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(8), vec![]),
+                    component(None, ParamType::Uint(16), vec![]),
+                ],
             }
         );
 
@@ -354,7 +393,12 @@ mod tests {
                     ])),
                     2
                 ),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(None, ParamType::Uint(48), vec![]),
+                    component(None, ParamType::Address, vec![]),
+                    component(None, ParamType::Address, vec![]),
+                ],
             }
         );
 
@@ -395,7 +439,22 @@ mod tests {
                     ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
                     ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42,)
                 ]),
-                internal_type: None
+                internal_type: None,
+                components: vec![
+                    component(
+                        None,
+                        ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                    component(
+                        None,
+                        ParamType::FixedArray(
+                            Box::new(ParamType::Tuple(vec![ParamType::Address])),
+                            42
+                        ),
+                        vec![component(None, ParamType::Address, vec![])]
+                    ),
+                ],
             }
         );
 
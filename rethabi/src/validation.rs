@@ -0,0 +1,438 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Structural validation of contracts.
+
+use alloc::collections::BTreeSet;
+use core::fmt;
+#[cfg(feature = "full-serde")]
+use std::io;
+
+#[cfg(feature = "full-serde")]
+use crate::errors;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{signature::signature_string, Contract, EventParam, Param, StateMutability};
+
+/// Maximum number of indexed params of a non-anonymous event.
+const MAX_INDEXED: usize = 3;
+
+/// Maximum number of indexed params of an anonymous event.
+const MAX_INDEXED_ANONYMOUS: usize = 4;
+
+/// Severity of a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The ABI can be loaded, but is likely a mistake.
+    Warning,
+    /// The ABI is invalid and can not be produced by a compiler.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Structural problem found in a contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Severity of the problem.
+    pub severity: Severity,
+    /// Item the problem was found in, e.g. `function transfer(address,uint256)`.
+    pub item: String,
+    /// Description of the problem.
+    pub reason: String,
+}
+
+impl Diagnostic {
+    fn error(item: &str, reason: String) -> Self {
+        Diagnostic { severity: Severity::Error, item: item.to_owned(), reason }
+    }
+
+    fn warning(item: &str, reason: String) -> Self {
+        Diagnostic { severity: Severity::Warning, item: item.to_owned(), reason }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.item, self.reason)
+    }
+}
+
+impl Contract {
+    /// Checks the contract for structural problems, such as duplicate overloads, events with
+    /// too many indexed params or invalid identifiers.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let Some(constructor) = &self.constructor {
+            check_params(&mut diagnostics, "constructor", &constructor.inputs);
+        }
+
+        let mut signatures = BTreeSet::new();
+        for function in self.functions() {
+            let item = format!("function {}", signature_string(&function.name, &function.inputs));
+            check_name(&mut diagnostics, &item, &function.name);
+            check_params(&mut diagnostics, &item, &function.inputs);
+            check_params(&mut diagnostics, &item, &function.outputs);
+            if !signatures.insert(item.clone()) {
+                diagnostics.push(Diagnostic::error(&item, "duplicate function".to_owned()));
+            }
+        }
+
+        for event in self.events() {
+            let item = format!("event {}", signature_string(&event.name, &event.inputs));
+            check_name(&mut diagnostics, &item, &event.name);
+            check_event_params(&mut diagnostics, &item, &event.inputs);
+
+            let indexed = event.inputs.iter().filter(|param| param.indexed).count();
+            let max = if event.anonymous { MAX_INDEXED_ANONYMOUS } else { MAX_INDEXED };
+            if indexed > max {
+                diagnostics.push(Diagnostic::error(
+                    &item,
+                    format!("{indexed} indexed params, at most {max} are allowed"),
+                ));
+            }
+
+            if !signatures
+                .insert(format!("{item}{}", if event.anonymous { " anonymous" } else { "" }))
+            {
+                diagnostics.push(Diagnostic::error(&item, "duplicate event".to_owned()));
+            }
+        }
+
+        for error in self.errors() {
+            let item = format!("error {}", signature_string(&error.name, &error.inputs));
+            check_name(&mut diagnostics, &item, &error.name);
+            check_params(&mut diagnostics, &item, &error.inputs);
+            if !signatures.insert(item.clone()) {
+                diagnostics.push(Diagnostic::error(&item, "duplicate error".to_owned()));
+            }
+        }
+
+        if let Some(receive) = &self.receive {
+            if receive.state_mutability != StateMutability::Payable {
+                diagnostics.push(Diagnostic::error(
+                    "receive",
+                    format!("must be payable, not {}", receive.state_mutability),
+                ));
+            }
+        }
+        if let Some(fallback) = &self.fallback {
+            if matches!(fallback.state_mutability, StateMutability::Pure | StateMutability::View) {
+                diagnostics.push(Diagnostic::error(
+                    "fallback",
+                    format!("must be payable or nonpayable, not {}", fallback.state_mutability),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Checks a contract ABI in json for structural problems.
+    ///
+    /// In addition to the checks of [`Contract::validate`], this reports problems that are lost
+    /// once the ABI is loaded, such as constructors with outputs or multiple constructors.
+    #[cfg(feature = "full-serde")]
+    pub fn validate_json<T: io::Read>(reader: T) -> errors::Result<Vec<Diagnostic>> {
+        let value: serde_json::Value = serde_json::from_reader(reader)?;
+
+        let mut diagnostics = Vec::new();
+        let mut constructors = 0;
+        let mut fallbacks = 0;
+        let mut receives = 0;
+        for item in value.as_array().into_iter().flatten() {
+            match item.get("type").and_then(serde_json::Value::as_str) {
+                Some("constructor") => {
+                    constructors += 1;
+                    if item
+                        .get("outputs")
+                        .and_then(serde_json::Value::as_array)
+                        .map_or(false, |outputs| !outputs.is_empty())
+                    {
+                        diagnostics.push(Diagnostic::error(
+                            "constructor",
+                            "constructors can not have outputs".to_owned(),
+                        ));
+                    }
+                }
+                Some("fallback") => fallbacks += 1,
+                Some("receive") => receives += 1,
+                _ => {}
+            }
+        }
+        for (item, count) in
+            [("constructor", constructors), ("fallback", fallbacks), ("receive", receives)]
+        {
+            if count > 1 {
+                diagnostics.push(Diagnostic::error(item, format!("declared {count} times")));
+            }
+        }
+
+        let contract: Contract = serde_json::from_value(value)?;
+        diagnostics.extend(contract.validate());
+        Ok(diagnostics)
+    }
+}
+
+fn check_name(diagnostics: &mut Vec<Diagnostic>, item: &str, name: &str) {
+    if !is_identifier(name) {
+        diagnostics.push(Diagnostic::error(item, format!("`{name}` is not a valid identifier")));
+    }
+}
+
+fn check_params(diagnostics: &mut Vec<Diagnostic>, item: &str, params: &[Param]) {
+    check_param_names(diagnostics, item, params.iter().map(|param| param.name.as_str()));
+}
+
+fn check_event_params(diagnostics: &mut Vec<Diagnostic>, item: &str, params: &[EventParam]) {
+    check_param_names(diagnostics, item, params.iter().map(|param| param.name.as_str()));
+}
+
+fn check_param_names<'a>(
+    diagnostics: &mut Vec<Diagnostic>,
+    item: &str,
+    names: impl Iterator<Item = &'a str>,
+) {
+    let mut seen = BTreeSet::new();
+    // Unnamed params are allowed and may repeat.
+    for name in names.filter(|name| !name.is_empty()) {
+        if !is_identifier(name) {
+            diagnostics.push(Diagnostic::warning(
+                item,
+                format!("param `{name}` is not a valid identifier"),
+            ));
+        } else if !seen.insert(name) {
+            diagnostics.push(Diagnostic::warning(item, format!("duplicate param `{name}`")));
+        }
+    }
+}
+
+/// Returns whether `name` is a valid Solidity identifier.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+    use crate::{Contract, Diagnostic, Severity};
+
+    fn validate(json: &str) -> Vec<Diagnostic> {
+        Contract::validate_json(json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn valid_contract() {
+        let json = r#"
+			[
+				{
+					"type": "constructor",
+					"inputs": [{ "name": "owner", "type": "address" }]
+				},
+				{
+					"type": "function",
+					"name": "foo",
+					"inputs": [{ "name": "a", "type": "uint256" }, { "name": "", "type": "bool" }],
+					"outputs": [{ "name": "", "type": "bool" }, { "name": "", "type": "bool" }]
+				},
+				{
+					"type": "function",
+					"name": "foo",
+					"inputs": [],
+					"outputs": []
+				},
+				{
+					"type": "event",
+					"name": "Foo",
+					"inputs": [
+						{ "name": "a", "type": "uint256", "indexed": true },
+						{ "name": "b", "type": "uint256", "indexed": true },
+						{ "name": "c", "type": "uint256", "indexed": true },
+						{ "name": "d", "type": "uint256", "indexed": false }
+					],
+					"anonymous": false
+				}
+			]
+		"#;
+
+        assert_eq!(validate(json), vec![]);
+    }
+
+    #[test]
+    fn duplicate_overloads() {
+        let json = r#"
+			[
+				{
+					"type": "function",
+					"name": "foo",
+					"inputs": [{ "name": "a", "type": "uint256" }],
+					"outputs": []
+				},
+				{
+					"type": "function",
+					"name": "foo",
+					"inputs": [{ "name": "b", "type": "uint256" }],
+					"outputs": [{ "name": "", "type": "bool" }]
+				},
+				{
+					"type": "error",
+					"name": "Bar",
+					"inputs": []
+				},
+				{
+					"type": "error",
+					"name": "Bar",
+					"inputs": []
+				}
+			]
+		"#;
+
+        assert_eq!(
+            validate(json),
+            vec![
+                Diagnostic {
+                    severity: Severity::Error,
+                    item: "function foo(uint256)".to_owned(),
+                    reason: "duplicate function".to_owned(),
+                },
+                Diagnostic {
+                    severity: Severity::Error,
+                    item: "error Bar()".to_owned(),
+                    reason: "duplicate error".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn too_many_indexed_params() {
+        let json = r#"
+			[
+				{
+					"type": "event",
+					"name": "Foo",
+					"inputs": [
+						{ "name": "a", "type": "bool", "indexed": true },
+						{ "name": "b", "type": "bool", "indexed": true },
+						{ "name": "c", "type": "bool", "indexed": true },
+						{ "name": "d", "type": "bool", "indexed": true }
+					],
+					"anonymous": false
+				},
+				{
+					"type": "event",
+					"name": "Bar",
+					"inputs": [
+						{ "name": "a", "type": "bool", "indexed": true },
+						{ "name": "b", "type": "bool", "indexed": true },
+						{ "name": "c", "type": "bool", "indexed": true },
+						{ "name": "d", "type": "bool", "indexed": true }
+					],
+					"anonymous": true
+				}
+			]
+		"#;
+
+        assert_eq!(
+            validate(json),
+            vec![Diagnostic {
+                severity: Severity::Error,
+                item: "event Foo(bool,bool,bool,bool)".to_owned(),
+                reason: "4 indexed params, at most 3 are allowed".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_identifiers() {
+        let json = r#"
+			[
+				{
+					"type": "function",
+					"name": "1foo",
+					"inputs": [{ "name": "a-b", "type": "uint256" }],
+					"outputs": [{ "name": "c", "type": "bool" }, { "name": "c", "type": "bool" }]
+				}
+			]
+		"#;
+
+        let diagnostics = validate(json);
+        assert_eq!(
+            diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "error: function 1foo(uint256): `1foo` is not a valid identifier",
+                "warning: function 1foo(uint256): param `a-b` is not a valid identifier",
+                "warning: function 1foo(uint256): duplicate param `c`",
+            ]
+        );
+    }
+
+    #[test]
+    fn constructor_with_outputs() {
+        let json = r#"
+			[
+				{
+					"type": "constructor",
+					"inputs": [],
+					"outputs": [{ "name": "", "type": "address" }]
+				},
+				{
+					"type": "constructor",
+					"inputs": []
+				}
+			]
+		"#;
+
+        assert_eq!(
+            validate(json),
+            vec![
+                Diagnostic {
+                    severity: Severity::Error,
+                    item: "constructor".to_owned(),
+                    reason: "constructors can not have outputs".to_owned(),
+                },
+                Diagnostic {
+                    severity: Severity::Error,
+                    item: "constructor".to_owned(),
+                    reason: "declared 2 times".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fallback_and_receive_mutability() {
+        let json = r#"
+			[
+				{ "type": "receive", "stateMutability": "nonpayable" },
+				{ "type": "fallback", "stateMutability": "view" }
+			]
+		"#;
+
+        assert_eq!(
+            validate(json).iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "error: receive: must be payable, not nonpayable",
+                "error: fallback: must be payable or nonpayable, not view",
+            ]
+        );
+    }
+}